@@ -0,0 +1,129 @@
+// Arithmetic for the places where a solver multiplies its way up to an answer. Normally these
+// are the plain operators, which wrap silently in a release build. With the `checked` feature
+// they panic on overflow instead, saying which operation overflowed and where it was called:
+//
+//   cargo run --release --features checked --bin 06 -- races.txt
+//
+//   panicked at src/year2023/day06.rs:34:34:
+//   day06 race: 39768070922 * 231929079 overflows i64
+//
+// `what` names the calculation in that message.
+use std::{
+    fmt,
+    ops::{Add, Mul, Sub},
+};
+
+pub trait Int:
+    Copy + fmt::Display + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const NAME: &'static str;
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const NAME: &'static str = stringify!($t);
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_add(self, rhs)
+                }
+
+                fn checked_sub(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, rhs)
+                }
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, rhs)
+                }
+            }
+        )*
+    };
+}
+
+impl_int!(i64, i128, u64, u128, usize);
+
+#[track_caller]
+pub fn add<T: Int>(what: &str, a: T, b: T) -> T {
+    if !cfg!(feature = "checked") {
+        return a + b;
+    }
+    match a.checked_add(b) {
+        Some(result) => result,
+        None => overflow(what, a, '+', b),
+    }
+}
+
+#[track_caller]
+pub fn sub<T: Int>(what: &str, a: T, b: T) -> T {
+    if !cfg!(feature = "checked") {
+        return a - b;
+    }
+    match a.checked_sub(b) {
+        Some(result) => result,
+        None => overflow(what, a, '-', b),
+    }
+}
+
+#[track_caller]
+pub fn mul<T: Int>(what: &str, a: T, b: T) -> T {
+    if !cfg!(feature = "checked") {
+        return a * b;
+    }
+    match a.checked_mul(b) {
+        Some(result) => result,
+        None => overflow(what, a, '*', b),
+    }
+}
+
+#[track_caller]
+pub fn sum<T: Int>(what: &str, values: impl IntoIterator<Item = T>) -> T {
+    let mut total = T::ZERO;
+    for value in values {
+        total = add(what, total, value);
+    }
+    total
+}
+
+#[track_caller]
+pub fn product<T: Int>(what: &str, values: impl IntoIterator<Item = T>) -> T {
+    let mut total = T::ONE;
+    for value in values {
+        total = mul(what, total, value);
+    }
+    total
+}
+
+#[cold]
+#[track_caller]
+fn overflow<T: Int>(what: &str, a: T, op: char, b: T) -> ! {
+    panic!("{}: {} {} {} overflows {}", what, a, op, b, T::NAME)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_arith() {
+        assert_eq!(sum("test", [1u64, 2, 3]), 6);
+        assert_eq!(product("test", [2i64, 3, 4]), 24);
+        assert_eq!(sub("test", mul("test", 3usize, 4), 2), 10);
+        assert_eq!(product("test", [u64::MAX as u128, 2]), u64::MAX as u128 * 2);
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    #[should_panic(expected = "test: 4294967296 * 4294967296 overflows u64")]
+    fn test_overflow_is_reported() {
+        product("test", [1u64 << 32, 1 << 32]);
+    }
+}
@@ -0,0 +1,464 @@
+use std::{
+    env, io,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use aoc2023::{
+    cache::Cache,
+    gen,
+    output::{Format, Printer},
+    runner::{self, Answer, Solution},
+    stream, trace,
+    years::{self, Year},
+};
+
+const USAGE: &str = "usage: aoc <command> [options]
+
+commands:
+  run <day|all> [--year <y>] [--input <file>] [--check] [--format <fmt>] [--no-cache] [--part <n>]
+      [--stats] [--stream] [--set <name=value>...]
+                                                solve a day, --check validates the input first,
+                                                answers are cached unless --no-cache, --stats
+                                                reports time and allocations (with the
+                                                count-allocs feature) per part on stderr,
+                                                --stream solves as it reads the input (days 1
+                                                to 4, 6, 7, 9, 12, 15, 18 and 24), uncached,
+                                                --input - reads stdin, --set changes one of
+                                                the day's settings (e.g. bag for day 2), a
+                                                value of @file reads it from the file
+  check-input <day|all> [--year <y>] [--input <file>]
+                                                check an input against a day's assumptions
+  generate <day> [--year <y>] [--seed <n>] [name=value...]
+                                                print a random input, --params lists the names
+  serve [--bind <addr>] [--max-body <bytes>] [--timeout <secs>] [--max-solves <n>]
+                                                answer POST /<year>/day/<n> over HTTP, needs the
+                                                serve feature
+  watch <day> [--year <y>] [--input <file>] [--example <file>]
+                                                re-run a day as it changes, needs the watch feature
+  tui [--year <y>]                              browse, run and inspect the days, needs the tui
+                                                feature
+
+options:
+  -v, -vv, -vvv          more output from every day
+  --trace <spec>         e.g. day20, day17=debug
+  --format <fmt>         how run prints answers: text (default), json or tsv
+  --year <y>             which year's puzzles, the latest by default";
+
+fn main() -> ExitCode {
+    let args = trace::init_from_args();
+    match run(&args) {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("{}\n\n{}", e, USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run(args: &[String]) -> Result<ExitCode, String> {
+    let (command, rest) = args.split_first().ok_or("no command given")?;
+
+    match command.as_str() {
+        "run" => {
+            let options = Options::parse(rest)?;
+            let mut printer = Printer::new(options.format, options.day.is_none());
+            let cache = Cache::default();
+            let mut ok = true;
+            for solution in options.solutions() {
+                if options.stream {
+                    solve_streaming(solution, &options, &mut printer)?;
+                    continue;
+                }
+                let input = options.load_input(solution)?;
+                if options.check {
+                    // Only the answers go to stdout.
+                    let results = solution.check_input(&input);
+                    if !runner::write_check_report(&mut io::stderr(), solution.day, &results)
+                        .map_err(|e| e.to_string())?
+                    {
+                        ok = false;
+                        continue;
+                    }
+                }
+                let use_cached = !options.no_cache;
+                printer.start_day(solution.year, solution.day);
+                for part in options.parts() {
+                    let answer = if options.stats {
+                        solve_part_with_stats(solution, part, &input)
+                    } else {
+                        cache.solve_part(solution, part, &input, use_cached)
+                    };
+                    printer.print_part(part, answer.as_ref());
+                }
+                printer.end_day();
+            }
+            Ok(exit_code(ok))
+        }
+        "check-input" => {
+            let options = Options::parse(rest)?;
+            let mut ok = true;
+            for solution in options.solutions() {
+                let input = options.load_input(solution)?;
+                let results = solution.check_input(&input);
+                ok &= runner::write_check_report(&mut io::stdout(), solution.day, &results)
+                    .map_err(|e| e.to_string())?;
+            }
+            Ok(exit_code(ok))
+        }
+        "generate" => generate(rest),
+        #[cfg(feature = "serve")]
+        "serve" => serve(rest),
+        #[cfg(not(feature = "serve"))]
+        "serve" => Err(
+            "aoc was built without the serve feature, rebuild with --features serve".to_string(),
+        ),
+        #[cfg(feature = "watch")]
+        "watch" => watch(rest),
+        #[cfg(not(feature = "watch"))]
+        "watch" => Err(
+            "aoc was built without the watch feature, rebuild with --features watch".to_string(),
+        ),
+        #[cfg(feature = "tui")]
+        "tui" => tui(rest),
+        #[cfg(not(feature = "tui"))]
+        "tui" => {
+            Err("aoc was built without the tui feature, rebuild with --features tui".to_string())
+        }
+        _ => Err(format!("unknown command '{}'", command)),
+    }
+}
+
+fn generate(args: &[String]) -> Result<ExitCode, String> {
+    let mut year = years::latest();
+    let mut day = None;
+    let mut seed = 0;
+    let mut list_params = false;
+    let mut overrides = vec![];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = args.next().ok_or("--seed needs a number")?;
+                seed = value.parse().map_err(|_| format!("bad seed '{}'", value))?;
+            }
+            "--params" => list_params = true,
+            "--year" => year = parse_year(args.next())?,
+            a => match (a.split_once('='), a.parse::<u8>()) {
+                (Some((name, value)), _) => {
+                    let value = value
+                        .parse::<i64>()
+                        .map_err(|_| format!("bad value for {}: '{}'", name, value))?;
+                    overrides.push((name.to_string(), value));
+                }
+                (None, Ok(n)) => day = Some(n),
+                _ => return Err(format!("unexpected argument '{}'", a)),
+            },
+        }
+    }
+    let day = day.ok_or("no day given")?;
+    let generator = year
+        .generator(day)
+        .ok_or_else(|| format!("no generator for {} day {}", year.year, day))?;
+
+    if list_params {
+        for param in generator.params {
+            println!("{:<12} {:>9}  {}", param.name, param.default, param.help);
+        }
+        return Ok(ExitCode::SUCCESS);
+    }
+    println!("{}", gen::generate(generator, seed, &overrides)?);
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(feature = "serve")]
+fn serve(args: &[String]) -> Result<ExitCode, String> {
+    use aoc2023::serve::{Config, Server};
+    use std::time::Duration;
+
+    let mut config = Config::default();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().ok_or(format!("{} needs a value", arg));
+        match arg.as_str() {
+            "--bind" => config.bind = value()?.clone(),
+            "--max-body" => {
+                let value = value()?;
+                config.max_body = value.parse().map_err(|_| format!("bad size '{}'", value))?;
+            }
+            "--timeout" => {
+                let value = value()?;
+                let secs: f64 = value
+                    .parse()
+                    .map_err(|_| format!("bad timeout '{}'", value))?;
+                config.timeout = Duration::try_from_secs_f64(secs)
+                    .map_err(|_| format!("bad timeout '{}'", value))?;
+            }
+            "--max-solves" => {
+                let value = value()?;
+                config.max_solves = match value.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("bad number of solves '{}'", value)),
+                };
+            }
+            a => return Err(format!("unexpected argument '{}'", a)),
+        }
+    }
+
+    let server = Server::bind(config.clone())
+        .map_err(|e| format!("could not listen on {}: {}", config.bind, e))?;
+    let addr = server.local_addr().map_err(|e| e.to_string())?;
+    eprintln!("listening on http://{}", addr);
+    server.run().map_err(|e| e.to_string())?;
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(feature = "watch")]
+fn watch(args: &[String]) -> Result<ExitCode, String> {
+    let mut year = years::latest();
+    let mut day = None;
+    let mut input = None;
+    let mut example = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = Some(PathBuf::from(args.next().ok_or("--input needs a file")?)),
+            "--example" => {
+                example = Some(PathBuf::from(args.next().ok_or("--example needs a file")?))
+            }
+            "--year" => year = parse_year(args.next())?,
+            d => match d.parse::<u8>() {
+                Ok(n) => day = Some(n),
+                _ => return Err(format!("unexpected argument '{}'", d)),
+            },
+        }
+    }
+    let day = solution(year, day.ok_or("no day given")?)?.day;
+
+    aoc2023::watch::watch(&aoc2023::watch::Config {
+        year: year.year,
+        day,
+        input: input.unwrap_or_else(|| runner::input_path(year.year, day)),
+        example,
+    })?;
+    Ok(ExitCode::SUCCESS)
+}
+
+#[cfg(feature = "tui")]
+fn tui(args: &[String]) -> Result<ExitCode, String> {
+    let mut year = years::latest();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--year" => year = parse_year(args.next())?,
+            a => return Err(format!("unexpected argument '{}'", a)),
+        }
+    }
+
+    aoc2023::tui::tui(&aoc2023::tui::Config { year })?;
+    Ok(ExitCode::SUCCESS)
+}
+
+// Solves the parts without the cache, reporting on each on stderr.
+fn solve_part_with_stats(solution: &Solution, part: u8, input: &str) -> Option<Answer> {
+    let solve = match part {
+        1 => solution.part1,
+        _ => solution.part2?,
+    };
+    let (answer, stats) = runner::measure(|| solve(input));
+    eprintln!("day {:02} part {}: {}", solution.day, part, stats);
+    Some(answer)
+}
+
+// Solves the parts as they read the input, without loading it or using the cache. Each part
+// reads it afresh, so stdin, which can only be read once, takes a --part.
+fn solve_streaming(
+    solution: &Solution,
+    options: &Options,
+    printer: &mut Printer,
+) -> Result<(), String> {
+    let streaming = solution.streaming.as_ref().ok_or_else(|| {
+        format!(
+            "{} day {} can't stream its input, run it without --stream",
+            solution.year, solution.day
+        )
+    })?;
+    let path = options.input_path(solution);
+    if path == Path::new("-") && options.part.is_none() {
+        return Err("streaming stdin needs a --part".to_string());
+    }
+    // InvalidInput is a bad setting and InvalidData a bad input, rather than trouble reading.
+    let read_error = |e: io::Error| match e.kind() {
+        io::ErrorKind::InvalidInput => e.to_string(),
+        io::ErrorKind::InvalidData => format!("{}: {}", path.display(), e),
+        _ => format!("could not read {}: {}", path.display(), e),
+    };
+
+    printer.start_day(solution.year, solution.day);
+    for part in options.parts() {
+        let Some(solve) = (match part {
+            1 => Some(streaming.part1),
+            _ => streaming.part2,
+        }) else {
+            continue;
+        };
+        let mut input = stream::open(&path).map_err(read_error)?;
+        let (answer, stats) = runner::measure(|| solve(&mut input));
+        if options.stats {
+            eprintln!("day {:02} part {}: {}", solution.day, part, stats);
+        }
+        printer.print_part(part, Some(&answer.map_err(read_error)?));
+    }
+    printer.end_day();
+    Ok(())
+}
+
+fn parse_year(value: Option<&String>) -> Result<&'static Year, String> {
+    let value = value.ok_or("--year needs a value")?;
+    value
+        .parse()
+        .ok()
+        .and_then(years::year)
+        .ok_or_else(|| format!("no solutions for year '{}'", value))
+}
+
+fn solution(year: &Year, day: u8) -> Result<&'static Solution, String> {
+    year.solution(day)
+        .ok_or_else(|| format!("no solution for {} day {}", year.year, day))
+}
+
+fn exit_code(ok: bool) -> ExitCode {
+    if ok {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+struct Options {
+    year: &'static Year,
+    // None means every day.
+    day: Option<u8>,
+    input: Option<PathBuf>,
+    check: bool,
+    format: Format,
+    // Solve again even if the answers are cached.
+    no_cache: bool,
+    // Only solve this part.
+    part: Option<u8>,
+    // Report how each part went on stderr, which means solving it.
+    stats: bool,
+    // Solve from the input as it is read, for inputs too big to load.
+    stream: bool,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut year = years::latest();
+        let mut day = None;
+        let mut input = None;
+        let mut check = false;
+        let mut format = Format::default();
+        let mut no_cache = false;
+        let mut part = None;
+        let mut stats = false;
+        let mut stream = false;
+        let mut settings = vec![];
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--input" => {
+                    let path = args.next().ok_or("--input needs a file")?;
+                    input = Some(PathBuf::from(path));
+                }
+                "--check" => check = true,
+                "--no-cache" => no_cache = true,
+                "--stats" => stats = true,
+                "--stream" => stream = true,
+                "--set" => {
+                    let setting = args.next().ok_or("--set needs name=value")?;
+                    let (name, value) = setting
+                        .split_once('=')
+                        .ok_or_else(|| format!("--set needs name=value, got '{}'", setting))?;
+                    settings.push((name.to_string(), value.to_string()));
+                }
+                "--format" => {
+                    format = Format::parse(args.next().ok_or("--format needs a value")?)?;
+                }
+                "--year" => year = parse_year(args.next())?,
+                "--part" => match args.next().map(String::as_str) {
+                    Some("1") => part = Some(1),
+                    Some("2") => part = Some(2),
+                    _ => return Err("--part needs 1 or 2".to_string()),
+                },
+                "all" => day = Some(None),
+                d => match d.parse::<u8>() {
+                    Ok(n) => day = Some(Some(n)),
+                    _ => return Err(format!("unexpected argument '{}'", d)),
+                },
+            }
+        }
+
+        let day = day.ok_or("no day given")?;
+        if let Some(day) = day {
+            solution(year, day)?;
+        }
+        if day.is_none() && input.is_some() {
+            return Err("--input only makes sense for a single day".to_string());
+        }
+        if stream && day.is_none() {
+            return Err("--stream only works for a single day".to_string());
+        }
+        if !settings.is_empty() {
+            let day = day.ok_or("--set only works for a single day")?;
+            // Before anything else runs, so no other thread can be reading the environment.
+            for (name, value) in settings {
+                env::set_var(runner::setting_var(year.year, day, &name), value);
+            }
+        }
+        if stream && check {
+            return Err(
+                "--check needs the whole input, it can't be used with --stream".to_string(),
+            );
+        }
+
+        Ok(Self {
+            year,
+            day,
+            input,
+            check,
+            format,
+            no_cache,
+            part,
+            stats,
+            stream,
+        })
+    }
+
+    fn solutions(&self) -> Vec<&'static Solution> {
+        match self.day {
+            Some(day) => vec![self.year.solution(day).unwrap()],
+            None => self.year.solutions.iter().collect(),
+        }
+    }
+
+    // The parts to solve, both unless --part picks one.
+    fn parts(&self) -> impl Iterator<Item = u8> + '_ {
+        (1..=2).filter(|part| self.part.is_none_or(|p| p == *part))
+    }
+
+    fn input_path(&self, solution: &Solution) -> PathBuf {
+        self.input
+            .clone()
+            .unwrap_or_else(|| runner::input_path(solution.year, solution.day))
+    }
+
+    fn load_input(&self, solution: &Solution) -> Result<String, String> {
+        let path = self.input_path(solution);
+        runner::load_input(&path).map_err(|e| format!("could not read {}: {}", path.display(), e))
+    }
+}
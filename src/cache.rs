@@ -0,0 +1,187 @@
+// Answers saved on disk, so `aoc run all` doesn't redo the slow days when nothing changed.
+//
+// An answer is keyed by year, day, part, the day's solver version and a hash of the input and
+// the day's settings, so editing an input, changing a setting or bumping a day's `version` in
+// year2023/mod.rs is enough to get it solved again. Each answer is a small file named after its
+// key:
+//
+//   target/answer-cache/2023-day17-part1-v1-5a0c3e9d1b2f4a67
+//
+// AOC_CACHE_DIR moves the cache elsewhere.
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    debug,
+    output::DayAnswers,
+    runner::{self, Answer, Solution},
+    warn,
+};
+
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Default for Cache {
+    fn default() -> Self {
+        let dir = env::var_os("AOC_CACHE_DIR")
+            .map(PathBuf::from)
+            .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("target/answer-cache"));
+        Self::at(dir)
+    }
+}
+
+impl Cache {
+    pub fn at(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    // Solves every part of a day, taking the answers from the cache where possible unless
+    // `use_cached` is false. Fresh answers are saved either way.
+    pub fn solve(&self, solution: &Solution, input: &str, use_cached: bool) -> DayAnswers {
+        DayAnswers {
+            year: solution.year,
+            day: solution.day,
+            part1: self.solve_part(solution, 1, input, use_cached),
+            part2: self.solve_part(solution, 2, input, use_cached),
+        }
+    }
+
+    // Like solve, for one part. None if the day doesn't have that part.
+    pub fn solve_part(
+        &self,
+        solution: &Solution,
+        part: u8,
+        input: &str,
+        use_cached: bool,
+    ) -> Option<Answer> {
+        let solve = match part {
+            1 => solution.part1,
+            _ => solution.part2?,
+        };
+        if use_cached {
+            if let Some(answer) = self.cached(solution, part, input) {
+                debug!(
+                    "cache",
+                    "{} day {} part {}: cached", solution.year, solution.day, part
+                );
+                return Some(answer);
+            }
+        }
+        let answer = solve(input);
+        let path = self.path(solution, part, input);
+        if let Err(e) = self.save(&path, &answer) {
+            warn!("cache", "could not save {}: {}", path.display(), e);
+        }
+        Some(answer)
+    }
+
+    // The saved answer to a part, without solving anything.
+    pub fn cached(&self, solution: &Solution, part: u8, input: &str) -> Option<Answer> {
+        fs::read_to_string(self.path(solution, part, input))
+            .ok()
+            .map(Answer::from)
+    }
+
+    fn path(&self, solution: &Solution, part: u8, input: &str) -> PathBuf {
+        let settings = runner::settings(solution.year, solution.day);
+        self.path_with(solution, part, input, &settings)
+    }
+
+    fn path_with(
+        &self,
+        solution: &Solution,
+        part: u8,
+        input: &str,
+        settings: &[(String, String)],
+    ) -> PathBuf {
+        let mut hash = fnv1a(FNV_OFFSET, input.as_bytes());
+        for (name, value) in settings {
+            hash = fnv1a(hash, format!("\0{}={}", name, value).as_bytes());
+        }
+        self.dir.join(format!(
+            "{}-day{:02}-part{}-v{}-{:016x}",
+            solution.year, solution.day, part, solution.version, hash
+        ))
+    }
+
+    // Written next to its final name and renamed into place, so a reader never sees half an
+    // answer.
+    fn save(&self, path: &Path, answer: &Answer) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        fs::write(&tmp, answer.to_string())?;
+        fs::rename(&tmp, path)
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+
+// 64 bit FNV-1a, carrying on from hash. Unlike std's hashers its output is stable across Rust
+// releases, which matters for names that are kept on disk.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &b| {
+        (hash ^ b as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    static SOLVED: AtomicUsize = AtomicUsize::new(0);
+
+    fn count_lines(input: &str) -> Answer {
+        SOLVED.fetch_add(1, Ordering::SeqCst);
+        input.lines().count().into()
+    }
+
+    #[test]
+    fn test_cache() {
+        let dir = env::temp_dir().join(format!("aoc-cache-test-{}", std::process::id()));
+        let cache = Cache::at(&dir);
+        let mut solution = Solution {
+            year: 2023,
+            day: 1,
+            version: 1,
+            part1: count_lines,
+            part2: None,
+            assumptions: &[],
+            streaming: None,
+        };
+        let solve = |solution: &Solution, input: &str, use_cached: bool| {
+            let before = SOLVED.load(Ordering::SeqCst);
+            let answer = cache.solve(solution, input, use_cached).part1.unwrap();
+            let answer = answer.to_string();
+            (answer, SOLVED.load(Ordering::SeqCst) - before)
+        };
+
+        assert_eq!(solve(&solution, "a\nb", true), ("2".to_string(), 1));
+        assert_eq!(solve(&solution, "a\nb", true), ("2".to_string(), 0));
+        assert_eq!(solve(&solution, "a\nb", false), ("2".to_string(), 1));
+        assert_eq!(solve(&solution, "a\nb\nc", true), ("3".to_string(), 1));
+        solution.version = 2;
+        assert_eq!(solve(&solution, "a\nb", true), ("2".to_string(), 1));
+        assert_eq!(cache.cached(&solution, 1, "a\nb"), Some(2.into()));
+        assert_eq!(cache.cached(&solution, 1, "a"), None);
+        assert_eq!(cache.solve_part(&solution, 2, "a", true), None);
+
+        // A day without settings, with the settings passed in rather than set in the
+        // environment the tests share.
+        solution.day = 99;
+        let setting = [("TEST".to_string(), "x".to_string())];
+        let path = cache.path(&solution, 1, "a");
+        assert_eq!(path, cache.path_with(&solution, 1, "a", &[]));
+        assert_ne!(path, cache.path_with(&solution, 1, "a", &setting));
+        assert_ne!(
+            cache.path_with(&solution, 1, "a", &setting),
+            cache.path_with(&solution, 1, "a", &[("TEST".to_string(), "y".to_string())])
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,176 @@
+// Seeded random inputs in the exact format of the puzzle input, so the solvers can be run
+// against more than the one input we were given:
+//
+//   cargo run --bin aoc -- generate 20 --seed 7 chains=6 bits=10
+//
+// Every generator describes its parameters (sizes, densities, ...) with a default close to the
+// shape of the real input. The same seed and parameters always give the same input. Each year
+// lists its generators next to its solutions, e.g. year2023/gen.
+use std::ops::Range;
+
+pub struct Generator {
+    pub day: u8,
+    pub params: &'static [Param],
+    pub generate: fn(&mut Rng, &Params) -> String,
+}
+
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    pub help: &'static str,
+}
+
+// The parameters a generator was asked for, falling back to the defaults it declared.
+pub struct Params {
+    spec: &'static [Param],
+    overrides: Vec<(String, i64)>,
+}
+
+impl Params {
+    pub fn new(spec: &'static [Param], overrides: &[(String, i64)]) -> Result<Self, String> {
+        for (name, _) in overrides {
+            if !spec.iter().any(|p| p.name == name) {
+                let known: Vec<&str> = spec.iter().map(|p| p.name).collect();
+                return Err(format!(
+                    "unknown parameter '{}', expected one of: {}",
+                    name,
+                    known.join(", ")
+                ));
+            }
+        }
+        Ok(Self {
+            spec,
+            overrides: overrides.to_vec(),
+        })
+    }
+
+    pub fn get(&self, name: &str) -> i64 {
+        match self.overrides.iter().rev().find(|(n, _)| n == name) {
+            Some((_, value)) => *value,
+            None => {
+                self.spec
+                    .iter()
+                    .find(|p| p.name == name)
+                    .unwrap_or_else(|| panic!("generator has no parameter '{}'", name))
+                    .default
+            }
+        }
+    }
+
+    // For counts and sizes, which make no sense below 1.
+    pub fn size(&self, name: &str) -> usize {
+        self.get(name).max(1) as usize
+    }
+}
+
+// Generates an input with the given seed, the overrides replacing the generator's defaults.
+pub fn generate(
+    generator: &Generator,
+    seed: u64,
+    overrides: &[(String, i64)],
+) -> Result<String, String> {
+    let params = Params::new(generator.params, overrides)?;
+    Ok((generator.generate)(&mut Rng::new(seed), &params))
+}
+
+// xoshiro256** seeded through splitmix64. Not cryptographic, but fast and reproducible across
+// platforms, which is all a generator needs.
+pub struct Rng {
+    s: [u64; 4],
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        let mut x = seed;
+        let mut splitmix = || {
+            x = x.wrapping_add(0x9e3779b97f4a7c15);
+            let mut z = x;
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+            z ^ (z >> 31)
+        };
+        Self {
+            s: [splitmix(), splitmix(), splitmix(), splitmix()],
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.s[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.s[1] << 17;
+        self.s[2] ^= self.s[0];
+        self.s[3] ^= self.s[1];
+        self.s[1] ^= self.s[2];
+        self.s[0] ^= self.s[3];
+        self.s[2] ^= t;
+        self.s[3] = self.s[3].rotate_left(45);
+        result
+    }
+
+    // Uniform in [0, n). Rejection sampling keeps it unbiased.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "empty range");
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return x % n;
+            }
+        }
+    }
+
+    pub fn range(&mut self, range: Range<i64>) -> i64 {
+        assert!(range.start < range.end, "empty range {:?}", range);
+        let width = range.end.abs_diff(range.start);
+        range.start.wrapping_add(self.below(width) as i64)
+    }
+
+    pub fn index(&mut self, n: usize) -> usize {
+        self.below(n as u64) as usize
+    }
+
+    // True with the given chance out of 100.
+    pub fn percent(&mut self, chance: i64) -> bool {
+        (self.below(100) as i64) < chance
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+
+    // A lowercase name that isn't in `taken` yet, which it is added to.
+    pub fn name(&mut self, len: usize, taken: &mut std::collections::HashSet<String>) -> String {
+        loop {
+            let name: String = (0..len)
+                .map(|_| (b'a' + self.below(26) as u8) as char)
+                .collect();
+            if taken.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const PARAMS: &[Param] = &[Param {
+        name: "ghosts",
+        default: 6,
+        help: "how many ghosts walk at once",
+    }];
+
+    #[test]
+    fn test_unknown_params_are_rejected() {
+        let params = Params::new(PARAMS, &[("ghosts".to_string(), 2)]).unwrap();
+        assert_eq!(params.get("ghosts"), 2);
+        assert_eq!(Params::new(PARAMS, &[]).unwrap().get("ghosts"), 6);
+        assert!(Params::new(PARAMS, &[("ghost".to_string(), 2)]).is_err());
+    }
+}
@@ -0,0 +1,26 @@
+// Just enough JSON output for the runner's machine readable results, without pulling in a
+// serialisation crate.
+
+// A JSON string literal holding s.
+pub fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+// A JSON string, or null.
+pub fn quote_opt(s: Option<&str>) -> String {
+    s.map(quote).unwrap_or_else(|| "null".to_string())
+}
@@ -0,0 +1,20 @@
+pub mod allocs;
+pub mod arith;
+pub mod cache;
+pub mod gen;
+pub mod json;
+pub mod memo;
+pub mod output;
+pub mod runner;
+#[cfg(feature = "serve")]
+pub mod serve;
+#[cfg(test)]
+mod snapshot;
+pub mod stream;
+pub mod trace;
+#[cfg(feature = "tui")]
+pub mod tui;
+#[cfg(feature = "watch")]
+pub mod watch;
+pub mod year2023;
+pub mod years;
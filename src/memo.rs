@@ -0,0 +1,134 @@
+// Memoization for recursive solvers, so they don't each need their own HashMap plumbing.
+//
+// The memo is passed down the recursion and each call goes through get_or_insert_with, which
+// hands the memo back to the closure for the recursive calls:
+//
+//   fn count(memo: &mut Memo<(usize, usize), u64>, i: usize, j: usize) -> u64 {
+//       memo.get_or_insert_with((i, j), |memo| count(memo, i + 1, j) + count(memo, i, j + 1))
+//   }
+//
+// Keys are cheapest as indices into the input rather than copies of slices of it. Indices are
+// only meaningful for one input, so clear the memo before moving on to the next.
+use std::{borrow::Borrow, collections::HashMap, fmt, hash::Hash};
+
+pub struct Memo<K, V> {
+    map: HashMap<K, V>,
+    hits: u64,
+    misses: u64,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub hits: u64,
+    pub misses: u64,
+    pub size: usize,
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} entries",
+            self.hits, self.misses, self.size
+        )
+    }
+}
+
+impl<K: Hash + Eq, V> Default for Memo<K, V> {
+    fn default() -> Self {
+        Self {
+            map: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl<K: Hash + Eq, V> Memo<K, V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Looks up a key by anything it can be borrowed as, e.g. a slice for a Vec key, so a hit
+    // doesn't need an owned key. Counts as a hit or a miss.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self.map.get(key) {
+            Some(value) => {
+                self.hits += 1;
+                Some(value)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.map.insert(key, value);
+    }
+
+    // The value for key, computing it with f on a miss. f gets the memo for its recursive calls.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce(&mut Self) -> V) -> V
+    where
+        V: Clone,
+    {
+        if let Some(value) = self.get(&key) {
+            return value.clone();
+        }
+        let value = f(self);
+        self.map.insert(key, value.clone());
+        value
+    }
+
+    // Hits and misses count since the memo was created, size is what it holds now.
+    pub fn stats(&self) -> Stats {
+        Stats {
+            hits: self.hits,
+            misses: self.misses,
+            size: self.map.len(),
+        }
+    }
+
+    // Forgets every value, keeping the allocation and the hit and miss counts.
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn paths(memo: &mut Memo<(u64, u64), u64>, x: u64, y: u64) -> u64 {
+        if x == 0 || y == 0 {
+            return 1;
+        }
+        memo.get_or_insert_with((x, y), |memo| paths(memo, x - 1, y) + paths(memo, x, y - 1))
+    }
+
+    #[test]
+    fn test_memo() {
+        let mut memo = Memo::new();
+        assert_eq!(paths(&mut memo, 16, 16), 601080390);
+        let stats = memo.stats();
+        assert_eq!((stats.misses, stats.size), (256, 256));
+        // Every call but the first is made by a miss, two per miss unless it hits an edge.
+        assert_eq!(stats.hits, 1 + 2 * 16 * 15 - 256);
+
+        memo.clear();
+        assert_eq!(memo.stats().size, 0);
+        assert_eq!(paths(&mut memo, 2, 1), 3);
+        assert_eq!(memo.stats().misses, 258);
+
+        let mut seen: Memo<Vec<char>, usize> = Memo::new();
+        seen.insert(vec!['#', '.'], 1);
+        assert_eq!(seen.get(&['#', '.'][..]), Some(&1));
+        assert_eq!(seen.get(&['.'][..]), None);
+        assert_eq!(seen.stats().to_string(), "1 hits, 1 misses, 1 entries");
+    }
+}
@@ -0,0 +1,256 @@
+// Every answer printed by the binaries goes through here, so scripts can ask for a format they
+// can parse instead of scraping the text:
+//
+//   cargo run --bin aoc -- run all --format tsv
+//
+// Only answers go to stdout. Diagnostics go to stderr, see trace.rs.
+use std::io::{self, Write};
+
+use crate::{json, runner::Answer};
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    // part1: 55123
+    #[default]
+    Text,
+    // One object per line: {"year":2023,"day":1,"part1":"55123","part2":"55260"}
+    Json,
+    // A header, then one row per day: year, day, part1, part2.
+    Tsv,
+}
+
+impl Format {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s {
+            "text" => Ok(Self::Text),
+            "json" => Ok(Self::Json),
+            "tsv" => Ok(Self::Tsv),
+            _ => Err(format!(
+                "unknown format '{}', expected text, json or tsv",
+                s
+            )),
+        }
+    }
+}
+
+pub struct DayAnswers {
+    pub year: u16,
+    pub day: u8,
+    // None for a part that wasn't asked for or that the day doesn't have.
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+// Text is printed a part at a time, as soon as each answer is in, since a slow part 2 can
+// take minutes. JSON and TSV print a day's answers together, as one line.
+pub struct Printer {
+    format: Format,
+    // Whether to say which day the answers are for in the text format.
+    label_days: bool,
+    started: bool,
+    // The answers so far of the day being printed, for JSON and TSV.
+    day: Option<DayAnswers>,
+}
+
+impl Printer {
+    pub fn new(format: Format, label_days: bool) -> Self {
+        Self {
+            format,
+            label_days,
+            started: false,
+            day: None,
+        }
+    }
+
+    pub fn print(&mut self, answers: &DayAnswers) {
+        to_stdout(|out| self.write(out, answers));
+    }
+
+    pub fn start_day(&mut self, year: u16, day: u8) {
+        to_stdout(|out| self.write_start_day(out, year, day));
+    }
+
+    pub fn print_part(&mut self, part: u8, answer: Option<&Answer>) {
+        to_stdout(|out| self.write_part(out, part, answer));
+    }
+
+    pub fn end_day(&mut self) {
+        to_stdout(|out| self.write_end_day(out));
+    }
+
+    pub fn write(&mut self, out: &mut impl Write, answers: &DayAnswers) -> io::Result<()> {
+        self.write_start_day(out, answers.year, answers.day)?;
+        self.write_part(out, 1, answers.part1.as_ref())?;
+        self.write_part(out, 2, answers.part2.as_ref())?;
+        self.write_end_day(out)
+    }
+
+    pub fn write_start_day(&mut self, out: &mut impl Write, year: u16, day: u8) -> io::Result<()> {
+        match self.format {
+            Format::Text if self.label_days => writeln!(out, "day {:02}", day)?,
+            Format::Text => {}
+            Format::Json | Format::Tsv => {
+                self.day = Some(DayAnswers {
+                    year,
+                    day,
+                    part1: None,
+                    part2: None,
+                })
+            }
+        }
+        Ok(())
+    }
+
+    // A part that wasn't asked for or that the day doesn't have has no answer.
+    pub fn write_part(
+        &mut self,
+        out: &mut impl Write,
+        part: u8,
+        answer: Option<&Answer>,
+    ) -> io::Result<()> {
+        match (self.format, &mut self.day) {
+            (Format::Text, _) => {
+                if let Some(answer) = answer {
+                    writeln!(out, "part{}: {}", part, answer)?;
+                }
+            }
+            (_, Some(day)) if part == 1 => day.part1 = answer.cloned(),
+            (_, Some(day)) => day.part2 = answer.cloned(),
+            (_, None) => panic!("part {} printed outside of a day", part),
+        }
+        Ok(())
+    }
+
+    pub fn write_end_day(&mut self, out: &mut impl Write) -> io::Result<()> {
+        let Some(answers) = self.day.take() else {
+            self.started = true;
+            return Ok(());
+        };
+        let part1 = answers.part1.as_ref().map(|a| a.to_string());
+        let part2 = answers.part2.as_ref().map(|a| a.to_string());
+        match self.format {
+            Format::Text => {}
+            Format::Json => writeln!(
+                out,
+                "{{\"year\":{},\"day\":{},\"part1\":{},\"part2\":{}}}",
+                answers.year,
+                answers.day,
+                json::quote_opt(part1.as_deref()),
+                json::quote_opt(part2.as_deref())
+            )?,
+            Format::Tsv => {
+                if !self.started {
+                    writeln!(out, "year\tday\tpart1\tpart2")?;
+                }
+                writeln!(
+                    out,
+                    "{}\t{}\t{}\t{}",
+                    answers.year,
+                    answers.day,
+                    part1.unwrap_or_default(),
+                    part2.unwrap_or_default()
+                )?;
+            }
+        }
+        self.started = true;
+        Ok(())
+    }
+}
+
+fn to_stdout(write: impl FnOnce(&mut io::StdoutLock<'static>) -> io::Result<()>) {
+    let mut stdout = io::stdout().lock();
+    write(&mut stdout)
+        .and_then(|()| stdout.flush())
+        .expect("failed to write to stdout");
+}
+
+// Reads back what the TSV format wrote, for the tools that run the binaries as children.
+pub fn parse_tsv(tsv: &str) -> Result<Vec<DayAnswers>, String> {
+    let mut lines = tsv.lines();
+    if lines.next() != Some("year\tday\tpart1\tpart2") {
+        return Err("missing the TSV header".to_string());
+    }
+    lines
+        .map(|line| {
+            let columns: Vec<&str> = line.split('\t').collect();
+            let [year, day, part1, part2] = columns[..] else {
+                return Err(format!("expected 4 columns in '{}'", line));
+            };
+            let answer = |a: &str| (!a.is_empty()).then(|| Answer::from(a.to_string()));
+            Ok(DayAnswers {
+                year: year.parse().map_err(|_| format!("bad year '{}'", year))?,
+                day: day.parse().map_err(|_| format!("bad day '{}'", day))?,
+                part1: answer(part1),
+                part2: answer(part2),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn render(format: Format, label_days: bool) -> String {
+        let mut printer = Printer::new(format, label_days);
+        let mut out = vec![];
+        for answers in [
+            DayAnswers {
+                year: 2023,
+                day: 24,
+                part1: Some(12.into()),
+                part2: Some(34.into()),
+            },
+            DayAnswers {
+                year: 2023,
+                day: 25,
+                part1: Some(56.into()),
+                part2: None,
+            },
+        ] {
+            printer.write(&mut out, &answers).unwrap();
+        }
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_formats() {
+        assert_eq!(
+            render(Format::Text, false),
+            "part1: 12\npart2: 34\npart1: 56\n"
+        );
+        assert_eq!(
+            render(Format::Text, true),
+            "day 24\npart1: 12\npart2: 34\nday 25\npart1: 56\n"
+        );
+        assert_eq!(
+            render(Format::Json, true),
+            "{\"year\":2023,\"day\":24,\"part1\":\"12\",\"part2\":\"34\"}\n{\"year\":2023,\"day\":25,\"part1\":\"56\",\"part2\":null}\n"
+        );
+        assert_eq!(
+            render(Format::Tsv, true),
+            "year\tday\tpart1\tpart2\n2023\t24\t12\t34\n2023\t25\t56\t\n"
+        );
+        assert!(Format::parse("xml").is_err());
+
+        // Text has part 1 out before part 2 is solved, the others wait for the whole day.
+        for (format, before_part2) in [
+            (Format::Text, "day 17\npart1: 12\n"),
+            (Format::Json, ""),
+            (Format::Tsv, ""),
+        ] {
+            let mut printer = Printer::new(format, true);
+            let mut out = vec![];
+            printer.write_start_day(&mut out, 2023, 17).unwrap();
+            printer.write_part(&mut out, 1, Some(&12.into())).unwrap();
+            assert_eq!(String::from_utf8(out).unwrap(), before_part2);
+        }
+
+        let parsed = parse_tsv(&render(Format::Tsv, true)).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!((parsed[1].year, parsed[1].day), (2023, 25));
+        assert_eq!(parsed[1].part1, Some(56.into()));
+        assert_eq!(parsed[1].part2, None);
+        assert!(parse_tsv("day\tpart1\n1\t2").is_err());
+    }
+}
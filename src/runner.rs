@@ -0,0 +1,288 @@
+// Shared plumbing for running the solutions: the registry types, input loading and the
+// assumption checks. Used both by the per-day binaries and by the `aoc` runner.
+use std::{
+    any::Any,
+    cell::Cell,
+    env, fmt, fs,
+    io::{self, BufRead, Write},
+    panic,
+    path::{Path, PathBuf},
+    sync::Once,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    allocs,
+    output::{Format, Printer},
+    trace, years,
+};
+
+// An answer to one part of a puzzle. Answers are only ever printed or compared, so keep them as
+// their decimal representation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Answer(String);
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+macro_rules! impl_answer_from {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+impl_answer_from!(i32, i64, i128, u32, u64, u128, usize, String);
+
+// Something a solution relies on that the puzzle text doesn't promise, but that held for our
+// input. `check` returns why the input breaks the assumption.
+pub struct Assumption {
+    pub name: &'static str,
+    pub check: fn(&str) -> Result<(), String>,
+}
+
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    // Bump whenever a change could change the day's answers, it throws away cached answers.
+    pub version: u32,
+    pub part1: fn(&str) -> Answer,
+    // Day 25 only has the one part.
+    pub part2: Option<fn(&str) -> Answer>,
+    pub assumptions: &'static [Assumption],
+    // The days that can solve their input as they read it, see src/stream.rs.
+    pub streaming: Option<Streaming>,
+}
+
+pub type StreamingPart = fn(&mut dyn BufRead) -> io::Result<Answer>;
+
+pub struct Streaming {
+    pub part1: StreamingPart,
+    pub part2: Option<StreamingPart>,
+}
+
+impl Solution {
+    // The checks parse the input the same way the solution does, so a badly broken input can
+    // make them panic. That counts as the assumption not holding.
+    pub fn check_input(&self, input: &str) -> Vec<(&'static str, Result<(), String>)> {
+        self.assumptions
+            .iter()
+            .map(|assumption| {
+                let result = catch_panic(|| (assumption.check)(input))
+                    .unwrap_or_else(|message| Err(format!("panicked: {}", message)));
+                (assumption.name, result)
+            })
+            .collect()
+    }
+}
+
+// Runs f, turning a panic into its message. Panics caught here aren't also printed to stderr.
+pub fn catch_panic<T>(f: impl FnOnce() -> T + panic::UnwindSafe) -> Result<T, String> {
+    install_quiet_hook();
+    let was_quiet = QUIET.with(|quiet| quiet.replace(true));
+    let result = panic::catch_unwind(f);
+    QUIET.with(|quiet| quiet.set(was_quiet));
+    result.map_err(|payload| panic_message(&*payload))
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+// Wraps the panic hook once, rather than swapping it around every call, so threads catching
+// panics at the same time can't leave each other's hook behind.
+fn install_quiet_hook() {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        let hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(|quiet| quiet.get()) {
+                hook(info);
+            }
+        }));
+    });
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+pub fn solution(year: u16, day: u8) -> Option<&'static Solution> {
+    years::year(year)?.solution(day)
+}
+
+pub fn input_path(year: u16, day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("inputs")
+        .join(year.to_string())
+        .join(format!("{:02}", day))
+}
+
+// Reads the whole input from the file at path, or from stdin for "-".
+pub fn load_input(path: &Path) -> io::Result<String> {
+    let input = if path == Path::new("-") {
+        io::read_to_string(io::stdin())?
+    } else {
+        fs::read_to_string(path)?
+    };
+    Ok(trim_input(&input).to_string())
+}
+
+// The solutions split on '\n' and don't expect a trailing empty line.
+pub fn trim_input(input: &str) -> &str {
+    input.trim_end_matches('\n')
+}
+
+// Settings change how a day solves, for variants of its puzzle. They are environment variables
+// named after the year, day and setting, which `aoc run --set name=value` sets too:
+//
+//   AOC_2023_DAY02_BAG="12 red, 13 green, 14 blue, 5 yellow"
+//
+// A value of @path is read from that file. Settings are part of the answer cache's key.
+pub fn setting_var(year: u16, day: u8, name: &str) -> String {
+    format!("AOC_{}_DAY{:02}_{}", year, day, name.to_ascii_uppercase())
+}
+
+pub fn setting(year: u16, day: u8, name: &str) -> Result<Option<String>, String> {
+    let var = setting_var(year, day, name);
+    match env::var(&var) {
+        Ok(value) => expand_setting(&value)
+            .map(Some)
+            .map_err(|e| format!("{}: {}", var, e)),
+        Err(env::VarError::NotPresent) => Ok(None),
+        Err(e) => Err(format!("{}: {}", var, e)),
+    }
+}
+
+// Every setting of a day, sorted by name, with files read.
+pub fn settings(year: u16, day: u8) -> Vec<(String, String)> {
+    let prefix = setting_var(year, day, "");
+    let mut settings: Vec<(String, String)> = env::vars()
+        .filter_map(|(var, value)| {
+            let name = var.strip_prefix(&prefix)?.to_string();
+            Some((name, expand_setting(&value).unwrap_or_else(|e| e)))
+        })
+        .collect();
+    settings.sort();
+    settings
+}
+
+fn expand_setting(value: &str) -> Result<String, String> {
+    match value.strip_prefix('@') {
+        Some(path) => {
+            fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path, e))
+        }
+        None => Ok(value.to_string()),
+    }
+}
+
+// Writes the outcome of every assumption check, returns whether they all passed.
+pub fn write_check_report(
+    out: &mut impl Write,
+    day: u8,
+    results: &[(&'static str, Result<(), String>)],
+) -> io::Result<bool> {
+    if results.is_empty() {
+        writeln!(out, "day {:02}: no assumptions to check", day)?;
+        return Ok(true);
+    }
+
+    let failed = results.iter().filter(|(_, r)| r.is_err()).count();
+    writeln!(
+        out,
+        "day {:02}: {}/{} assumptions hold",
+        day,
+        results.len() - failed,
+        results.len()
+    )?;
+    for (name, result) in results {
+        match result {
+            Ok(()) => writeln!(out, "  ok    {}", name)?,
+            Err(reason) => writeln!(out, "  FAIL  {}: {}", name, reason)?,
+        }
+    }
+    Ok(failed == 0)
+}
+
+// How long a part took, and what it allocated with the count-allocs feature.
+#[derive(Copy, Clone, Debug)]
+pub struct PartStats {
+    pub elapsed: Duration,
+    pub allocs: Option<allocs::Stats>,
+}
+
+impl fmt::Display for PartStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1?}", self.elapsed)?;
+        if let Some(allocs) = &self.allocs {
+            write!(f, ", {}", allocs)?;
+        }
+        Ok(())
+    }
+}
+
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, PartStats) {
+    let start = Instant::now();
+    let (result, allocs) = allocs::measure(f);
+    let stats = PartStats {
+        elapsed: start.elapsed(),
+        allocs,
+    };
+    (result, stats)
+}
+
+// Entry point of src/bin/NN.rs. Solves the day for its usual input, or for the file passed as
+// an argument. `--format text|json|tsv` picks how the answers are printed.
+pub fn day_main(year: u16, day: u8) {
+    let (format, path) = match parse_day_args(trace::init_from_args()) {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    };
+    let path = path.unwrap_or_else(|| input_path(year, day));
+    let input = match load_input(&path) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("could not read {}: {}", path.display(), e);
+            std::process::exit(1);
+        }
+    };
+
+    let solution = solution(year, day).unwrap();
+    let mut printer = Printer::new(format, false);
+    printer.start_day(year, day);
+    printer.print_part(1, Some(&(solution.part1)(&input)));
+    printer.print_part(2, solution.part2.map(|part2| part2(&input)).as_ref());
+    printer.end_day();
+}
+
+fn parse_day_args(args: Vec<String>) -> Result<(Format, Option<PathBuf>), String> {
+    let mut format = Format::default();
+    let mut path = None;
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => format = Format::parse(&args.next().ok_or("--format needs a value")?)?,
+            _ if path.is_none() => path = Some(PathBuf::from(arg)),
+            a => return Err(format!("unexpected argument '{}'", a)),
+        }
+    }
+    Ok((format, path))
+}
@@ -0,0 +1,583 @@
+// A small local HTTP server in front of the solvers, so other tools can use them without
+// shelling out to the binaries:
+//
+//   cargo run --release --features serve --bin aoc -- serve --bind 127.0.0.1:8023
+//   curl --data-binary @inputs/2023/01 localhost:8023/2023/day/1
+//
+// POST /{year}/day/{n}, or /day/{n} for the latest year, with the raw puzzle input as the body
+// answers with
+//
+//   {"part1":"55123","part2":"55260","timings":{"part1_ms":0.412,"part2_ms":1.250},"error":null}
+//
+// Answers are strings because not all of them fit in a double. A part is null if the day
+// doesn't have it or it didn't produce an answer, and `error` says why. Every response, errors
+// included, has the same shape.
+//
+// It is meant for localhost only: a thread per connection and one request per connection. There
+// are only so many of either at a time, past that requests get a 503.
+use std::{
+    collections::HashMap,
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        mpsc, Arc, Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    debug, info, json,
+    runner::{self, Answer, Solution},
+    years,
+};
+
+// How long a client may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+const MAX_HEADERS: u64 = 8 * 1024;
+
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub bind: String,
+    // Largest request body accepted, in bytes.
+    pub max_body: usize,
+    // How long a request may spend solving. The solvers can't be interrupted, so a solver that
+    // runs out of time carries on in the background, it just doesn't get to answer. Until it
+    // is done it still counts towards max_solves, and its day takes no new requests.
+    pub timeout: Duration,
+    // Most solves running at once.
+    pub max_solves: usize,
+    // Most connections handled at once.
+    pub max_connections: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            bind: "127.0.0.1:8023".to_string(),
+            max_body: 1024 * 1024,
+            timeout: Duration::from_secs(60),
+            max_solves: 4,
+            max_connections: 64,
+        }
+    }
+}
+
+pub struct Server {
+    listener: TcpListener,
+    config: Config,
+}
+
+impl Server {
+    pub fn bind(config: Config) -> io::Result<Self> {
+        let listener = TcpListener::bind(&config.bind)?;
+        Ok(Self { listener, config })
+    }
+
+    // Useful when binding to port 0.
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    pub fn run(self) -> io::Result<()> {
+        let solves = Arc::new(Solves::default());
+        let connections = Arc::new(AtomicUsize::new(0));
+        for stream in self.listener.incoming() {
+            let stream = stream?;
+            if connections.fetch_add(1, Ordering::SeqCst) >= self.config.max_connections {
+                connections.fetch_sub(1, Ordering::SeqCst);
+                // Turned away without reading the request, so this can't hold up the others.
+                let response = Response::error(503, "too many connections, try again later");
+                let refused = stream
+                    .set_write_timeout(Some(READ_TIMEOUT))
+                    .and_then(|_| write_response(&stream, &response));
+                if let Err(e) = refused {
+                    debug!("serve", "dropped connection: {}", e);
+                }
+                continue;
+            }
+
+            let config = self.config.clone();
+            let solves = solves.clone();
+            let connections = connections.clone();
+            thread::spawn(move || {
+                if let Err(e) = handle(stream, &config, &solves) {
+                    debug!("serve", "dropped connection: {}", e);
+                }
+                connections.fetch_sub(1, Ordering::SeqCst);
+            });
+        }
+        Ok(())
+    }
+}
+
+// The solves running for requests, the ones whose request has timed out included.
+#[derive(Default)]
+struct Solves {
+    running: Mutex<HashMap<u64, RunningSolve>>,
+    next_id: AtomicU64,
+}
+
+struct RunningSolve {
+    year: u16,
+    day: u8,
+    timed_out: bool,
+}
+
+impl Solves {
+    fn start(self: &Arc<Self>, solution: &Solution, max: usize) -> Result<SolveSlot, Response> {
+        let mut running = self.running.lock().unwrap();
+        if running
+            .values()
+            .any(|s| s.timed_out && (s.year, s.day) == (solution.year, solution.day))
+        {
+            return Err(Response::error(
+                503,
+                &format!(
+                    "{} day {} is still solving a request that timed out",
+                    solution.year, solution.day
+                ),
+            ));
+        }
+        if running.len() >= max {
+            return Err(Response::error(
+                503,
+                "too many solves running, try again later",
+            ));
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        running.insert(
+            id,
+            RunningSolve {
+                year: solution.year,
+                day: solution.day,
+                timed_out: false,
+            },
+        );
+        Ok(SolveSlot {
+            solves: self.clone(),
+            id,
+        })
+    }
+
+    fn finish(&self, id: u64) {
+        self.running.lock().unwrap().remove(&id);
+    }
+
+    fn time_out(&self, id: u64) {
+        if let Some(solve) = self.running.lock().unwrap().get_mut(&id) {
+            solve.timed_out = true;
+        }
+    }
+}
+
+// Held by the thread doing a solve, frees its place when the thread is done.
+struct SolveSlot {
+    solves: Arc<Solves>,
+    id: u64,
+}
+
+impl Drop for SolveSlot {
+    fn drop(&mut self) {
+        self.solves.finish(self.id);
+    }
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn error(status: u16, error: &str) -> Self {
+        Self {
+            status,
+            body: format!(
+                "{{\"part1\":null,\"part2\":null,\"timings\":{{}},\"error\":{}}}",
+                json::quote(error)
+            ),
+        }
+    }
+}
+
+fn handle(stream: TcpStream, config: &Config, solves: &Arc<Solves>) -> io::Result<()> {
+    let start = Instant::now();
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+
+    let (request_line, response) = match read_head(&mut reader) {
+        Ok(head) => {
+            let response = respond(&head, &mut reader, config, solves)?;
+            (head.request_line, response)
+        }
+        Err(response) => (String::new(), response),
+    };
+
+    info!(
+        "serve",
+        "{} -> {} in {:.1?}",
+        request_line,
+        response.status,
+        start.elapsed()
+    );
+    write_response(&stream, &response)
+}
+
+fn write_response(mut stream: &TcpStream, response: &Response) -> io::Result<()> {
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason(response.status),
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+struct Head {
+    request_line: String,
+    method: String,
+    path: String,
+    content_length: Option<usize>,
+}
+
+// Reads the request line and headers, up to the blank line before the body.
+fn read_head(reader: &mut BufReader<&TcpStream>) -> Result<Head, Response> {
+    let mut limited = reader.take(MAX_HEADERS);
+    let mut lines = vec![];
+    loop {
+        let mut line = String::new();
+        match limited.read_line(&mut line) {
+            Ok(0) if limited.limit() == 0 => {
+                return Err(Response::error(431, "request headers too large"))
+            }
+            Ok(0) => return Err(Response::error(400, "incomplete request")),
+            Ok(_) => {}
+            Err(e) => return Err(Response::error(400, &e.to_string())),
+        }
+        let line = line.trim_end_matches(['\r', '\n']).to_string();
+        if line.is_empty() {
+            break;
+        }
+        lines.push(line);
+    }
+
+    let request_line = lines
+        .first()
+        .ok_or_else(|| Response::error(400, "empty request"))?
+        .clone();
+    let mut parts = request_line.split(' ');
+    let (method, path) = match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => (method.to_string(), path.to_string()),
+        _ => return Err(Response::error(400, "malformed request line")),
+    };
+
+    let mut content_length = None;
+    for header in &lines[1..] {
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                let length = value
+                    .trim()
+                    .parse()
+                    .map_err(|_| Response::error(400, "bad Content-Length"))?;
+                content_length = Some(length);
+            }
+        }
+    }
+
+    Ok(Head {
+        request_line,
+        method,
+        path,
+        content_length,
+    })
+}
+
+fn respond(
+    head: &Head,
+    reader: &mut BufReader<&TcpStream>,
+    config: &Config,
+    solves: &Arc<Solves>,
+) -> io::Result<Response> {
+    let solution = match route(&head.path) {
+        Some(solution) => solution,
+        None => {
+            return Ok(Response::error(
+                404,
+                "expected /{year}/day/{n} or /day/{n} for a day we have solved",
+            ))
+        }
+    };
+    if head.method != "POST" {
+        return Ok(Response::error(405, "POST the puzzle input"));
+    }
+    let length = match head.content_length {
+        Some(length) if length > config.max_body => {
+            return Ok(Response::error(
+                413,
+                &format!("the input is limited to {} bytes", config.max_body),
+            ))
+        }
+        Some(length) => length,
+        None => return Ok(Response::error(411, "Content-Length is required")),
+    };
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+    let input = match String::from_utf8(body) {
+        Ok(input) => input,
+        Err(_) => return Ok(Response::error(400, "the input is not UTF-8")),
+    };
+
+    let slot = match solves.start(solution, config.max_solves) {
+        Ok(slot) => slot,
+        Err(response) => return Ok(response),
+    };
+    Ok(solve(solution, input, config.timeout, slot))
+}
+
+fn route(path: &str) -> Option<&'static Solution> {
+    let (year, day) = match path.strip_prefix("/day/") {
+        Some(day) => (years::latest(), day),
+        None => {
+            let (year, day) = path.strip_prefix('/')?.split_once("/day/")?;
+            (years::year(year.parse().ok()?)?, day)
+        }
+    };
+    year.solution(day.parse().ok()?)
+}
+
+type PartResult = (usize, Result<Answer, String>, Duration);
+
+// Solves both parts on another thread, which reports each part as soon as it is done. Whatever
+// has been reported when the time runs out is still returned.
+fn solve(
+    solution: &'static Solution,
+    input: String,
+    timeout: Duration,
+    slot: SolveSlot,
+) -> Response {
+    let parts: Vec<fn(&str) -> Answer> = [Some(solution.part1), solution.part2]
+        .into_iter()
+        .flatten()
+        .collect();
+    let expected = parts.len();
+
+    let (sender, receiver) = mpsc::channel::<PartResult>();
+    let solves = slot.solves.clone();
+    let id = slot.id;
+    thread::spawn(move || {
+        let _slot = slot;
+        let input = runner::trim_input(&input);
+        for (i, part) in parts.into_iter().enumerate() {
+            let start = Instant::now();
+            let result = runner::catch_panic(|| part(input));
+            // The request may have given up on us already.
+            if sender.send((i, result, start.elapsed())).is_err() {
+                return;
+            }
+        }
+    });
+
+    let deadline = Instant::now() + timeout;
+    let mut results: Vec<PartResult> = vec![];
+    let mut timed_out = false;
+    while results.len() < expected {
+        match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+            Ok(result) => results.push(result),
+            Err(_) => {
+                timed_out = true;
+                solves.time_out(id);
+                break;
+            }
+        }
+    }
+
+    let mut answers = [None, None];
+    let mut timings = vec![];
+    let mut errors = vec![];
+    for (i, result, elapsed) in results {
+        timings.push(format!(
+            "\"part{}_ms\":{:.3}",
+            i + 1,
+            elapsed.as_secs_f64() * 1000.0
+        ));
+        match result {
+            Ok(answer) => answers[i] = Some(answer.to_string()),
+            Err(message) => errors.push(format!("part{} panicked: {}", i + 1, message)),
+        }
+    }
+    if timed_out {
+        errors.push(format!("no answer within {:?}", timeout));
+    } else {
+        // Every part has answered, so don't wait for the thread to wind down before the next
+        // request can have its place.
+        solves.finish(id);
+    }
+
+    let status = if timed_out {
+        504
+    } else if !errors.is_empty() {
+        422
+    } else {
+        200
+    };
+    let error = if errors.is_empty() {
+        None
+    } else {
+        Some(errors.join("; "))
+    };
+    Response {
+        status,
+        body: format!(
+            "{{\"part1\":{},\"part2\":{},\"timings\":{{{}}},\"error\":{}}}",
+            json::quote_opt(answers[0].as_deref()),
+            json::quote_opt(answers[1].as_deref()),
+            timings.join(","),
+            json::quote_opt(error.as_deref())
+        ),
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        431 => "Request Header Fields Too Large",
+        503 => "Service Unavailable",
+        504 => "Gateway Timeout",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::AtomicBool;
+
+    use super::*;
+
+    fn start(config: Config) -> SocketAddr {
+        let server = Server::bind(Config {
+            bind: "127.0.0.1:0".to_string(),
+            ..config
+        })
+        .unwrap();
+        let addr = server.local_addr().unwrap();
+        thread::spawn(move || server.run());
+        addr
+    }
+
+    fn request(addr: SocketAddr, request: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let body = response.split_once("\r\n\r\n").unwrap().1.to_string();
+        (status, body)
+    }
+
+    fn post(addr: SocketAddr, path: &str, input: &str) -> (u16, String) {
+        request(
+            addr,
+            &format!(
+                "POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+                path,
+                input.len(),
+                input
+            ),
+        )
+    }
+
+    #[test]
+    fn test_serve() {
+        let addr = start(Config {
+            max_body: 100,
+            max_solves: 1,
+            ..Config::default()
+        });
+
+        let (status, body) = post(
+            addr,
+            "/day/1",
+            "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n",
+        );
+        assert_eq!(status, 200);
+        assert!(body.starts_with(r#"{"part1":"142","part2":"142","timings":{"part1_ms":"#));
+        assert!(body.ends_with(r#"},"error":null}"#));
+
+        let (status, body) = post(addr, "/day/4", "not a card");
+        assert_eq!(status, 422);
+        assert!(body.starts_with(r#"{"part1":null,"part2":null,"#));
+        assert!(body.contains(r#""error":"part1 panicked: "#));
+
+        assert_eq!(post(addr, "/day/26", "").0, 404);
+        let (status, body) = post(addr, "/2023/day/1", "1abc2");
+        assert_eq!(status, 200);
+        assert!(body.starts_with(r#"{"part1":"12","part2":"12","#));
+        assert_eq!(post(addr, "/2015/day/1", "").0, 404);
+        assert_eq!(post(addr, "/day/1", &"1".repeat(101)).0, 413);
+        assert_eq!(request(addr, "GET /day/1 HTTP/1.1\r\n\r\n").0, 405);
+        assert_eq!(request(addr, "POST /day/1 HTTP/1.1\r\n\r\n").0, 411);
+    }
+
+    // Part 1 of a day that holds on until it is let go, like a solve that takes too long.
+    static HOLD: AtomicBool = AtomicBool::new(true);
+
+    fn held(_: &str) -> Answer {
+        while HOLD.load(Ordering::SeqCst) {
+            thread::sleep(Duration::from_millis(1));
+        }
+        0.into()
+    }
+
+    static HELD: Solution = Solution {
+        year: 2023,
+        day: 6,
+        version: 1,
+        part1: held,
+        part2: None,
+        assumptions: &[],
+        streaming: None,
+    };
+
+    #[test]
+    fn test_serve_timeout() {
+        let solves = Arc::new(Solves::default());
+        let slot = solves.start(&HELD, 1).ok().unwrap();
+        let response = solve(&HELD, String::new(), Duration::from_millis(50), slot);
+        assert_eq!(response.status, 504);
+        assert_eq!(
+            response.body,
+            r#"{"part1":null,"part2":null,"timings":{},"error":"no answer within 50ms"}"#
+        );
+
+        // That solve is still going, so day 6 is refused, and it has the only place to solve in.
+        let refused = solves.start(&HELD, 1).err().unwrap();
+        assert_eq!(refused.status, 503);
+        assert!(refused
+            .body
+            .contains("2023 day 6 is still solving a request that timed out"));
+        let day1 = years::latest().solution(1).unwrap();
+        let refused = solves.start(day1, 1).err().unwrap();
+        assert_eq!(refused.status, 503);
+        assert!(refused.body.contains("too many solves running"));
+
+        // Once it is done its place is free again.
+        HOLD.store(false, Ordering::SeqCst);
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while !solves.running.lock().unwrap().is_empty() {
+            assert!(Instant::now() < deadline, "the held solve never finished");
+            thread::sleep(Duration::from_millis(1));
+        }
+        assert!(solves.start(&HELD, 1).is_ok());
+    }
+}
@@ -0,0 +1,66 @@
+// Golden-file tests for the renderers. A test renders something from an example input and
+// compares it with the file checked in under snapshots/. After a change to a renderer,
+//
+//   UPDATE_SNAPSHOTS=1 cargo test
+//
+// rewrites the files of every snapshot test that runs, and the diff shows what changed.
+use std::{env, fs, path::Path};
+
+use itertools::{EitherOrBoth, Itertools};
+
+#[track_caller]
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("snapshots")
+        .join(name);
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+
+    let expected = fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "could not read {}: {}, run with UPDATE_SNAPSHOTS=1 to create it",
+            path.display(),
+            e
+        )
+    });
+    if let Some(difference) = first_difference(&expected, actual) {
+        panic!(
+            "{} doesn't match, {}\nrun with UPDATE_SNAPSHOTS=1 if the change is intended",
+            path.display(),
+            difference
+        );
+    }
+}
+
+// Where two renderings first part ways, with the lines on either side.
+fn first_difference(expected: &str, actual: &str) -> Option<String> {
+    expected
+        .split('\n')
+        .zip_longest(actual.split('\n'))
+        .enumerate()
+        .find(|(_, lines)| !matches!(lines, EitherOrBoth::Both(e, a) if e == a))
+        .map(|(i, lines)| {
+            let (e, a) = lines.map_any(Some, Some).or(None, None);
+            format!("line {}:\n  expected: {:?}\n  actual:   {:?}", i + 1, e, a)
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_first_difference() {
+        assert_eq!(first_difference("a\nb\n", "a\nb\n"), None);
+        assert_eq!(
+            first_difference("a\nb\n", "a\nc\n").unwrap(),
+            "line 2:\n  expected: Some(\"b\")\n  actual:   Some(\"c\")"
+        );
+        assert!(first_difference("a\n", "a\nb\n")
+            .unwrap()
+            .starts_with("line 2:"));
+    }
+}
@@ -0,0 +1,119 @@
+// Reading an input a record at a time, for the days that don't need all of it at once. Generated
+// stress inputs run to hundreds of MB, more than is sensible to load, so these days can also be
+// run straight from a file or a pipe:
+//
+//   aoc generate 9 lines=5000000 | aoc run 9 --stream --part 1 --input -
+//
+// A record is the bytes up to a delimiter, a line being a record ending in '\n'. The same buffer
+// is reused for every record, so a day that only keeps running totals solves any size of input
+// in the memory of its longest line.
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+    path::Path,
+    str,
+};
+
+use crate::runner::{Answer, StreamingPart};
+
+pub struct Records<R> {
+    reader: R,
+    delimiter: u8,
+    buf: Vec<u8>,
+}
+
+pub fn lines<R: BufRead>(reader: R) -> Records<R> {
+    records(reader, b'\n')
+}
+
+pub fn records<R: BufRead>(reader: R, delimiter: u8) -> Records<R> {
+    Records {
+        reader,
+        delimiter,
+        buf: vec![],
+    }
+}
+
+impl<R: BufRead> Records<R> {
+    // The next record without its delimiter, or None at the end of the input. Line endings are
+    // trimmed off every record, and records left empty are skipped, so neither a trailing
+    // newline nor "\r\n" makes a difference.
+    pub fn next_bytes(&mut self) -> io::Result<Option<&[u8]>> {
+        loop {
+            self.buf.clear();
+            if self.reader.read_until(self.delimiter, &mut self.buf)? == 0 {
+                return Ok(None);
+            }
+            if self.buf.last() == Some(&self.delimiter) {
+                self.buf.pop();
+            }
+            while let Some(b'\n' | b'\r') = self.buf.last() {
+                self.buf.pop();
+            }
+            if !self.buf.is_empty() {
+                return Ok(Some(&self.buf));
+            }
+        }
+    }
+
+    // Like next_bytes, for the days that parse text.
+    pub fn next_str(&mut self) -> io::Result<Option<&str>> {
+        match self.next_bytes()? {
+            Some(bytes) => str::from_utf8(bytes)
+                .map(Some)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            None => Ok(None),
+        }
+    }
+}
+
+// Reads the file at path as it goes, or stdin for "-".
+pub fn open(path: &Path) -> io::Result<Box<dyn BufRead>> {
+    if path == Path::new("-") {
+        return Ok(Box::new(io::stdin().lock()));
+    }
+    Ok(Box::new(BufReader::new(File::open(path)?)))
+}
+
+// Runs a streaming part on an input that is already loaded. Reading from memory can't fail, so
+// an error here is a bad input or setting, and panics as those do.
+pub fn solve_str(part: StreamingPart, input: &str) -> Answer {
+    part(&mut input.as_bytes()).unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn collect<R: BufRead>(mut records: Records<R>) -> Vec<String> {
+        let mut all = vec![];
+        while let Some(record) = records.next_str().unwrap() {
+            all.push(record.to_string());
+        }
+        all
+    }
+
+    #[test]
+    fn test_records() {
+        // A tiny buffer, so records are split across reads.
+        let reader = |s: &'static str| BufReader::with_capacity(3, s.as_bytes());
+
+        assert_eq!(
+            collect(lines(reader("ab\ncdefg\r\nh"))),
+            ["ab", "cdefg", "h"]
+        );
+        assert_eq!(collect(lines(reader("ab\n\ncd\n\n"))), ["ab", "cd"]);
+        assert_eq!(collect(lines(reader(""))), Vec::<String>::new());
+        assert_eq!(
+            collect(records(reader("rn=1,cm-,qp=3\n"), b',')),
+            ["rn=1", "cm-", "qp=3"]
+        );
+
+        let mut bad = lines(&b"ok\n\xff\n"[..]);
+        assert_eq!(bad.next_str().unwrap(), Some("ok"));
+        assert_eq!(
+            bad.next_str().unwrap_err().kind(),
+            io::ErrorKind::InvalidData
+        );
+    }
+}
@@ -0,0 +1,290 @@
+// Leveled debug output for the solvers.
+//
+// Every day logs under its own target ("day17", "day20", ...), so a single day can be made
+// chatty without drowning in the output of the others:
+//
+//   cargo run --bin 20 -- --trace day20          (everything day 20 has to say)
+//   cargo run --bin 17 -- --trace day17=debug    (just the debug output of day 17)
+//   cargo run --bin 17 -- -vv                    (debug output of every target)
+//
+// Everything is written to stderr so it never gets mixed up with the answers.
+use std::{
+    fmt,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        OnceLock,
+    },
+};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    fn from_str(s: &str) -> Option<Self> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Some(Self::Error),
+            "warn" => Some(Self::Warn),
+            "info" => Some(Self::Info),
+            "debug" => Some(Self::Debug),
+            "trace" => Some(Self::Trace),
+            _ => None,
+        }
+    }
+
+    // The level enabled by passing -v n times.
+    fn from_verbosity(n: usize) -> Self {
+        match n {
+            0 => Self::Warn,
+            1 => Self::Info,
+            2 => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+        f.write_str(s)
+    }
+}
+
+// Which targets are enabled at which level.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Filter {
+    // Applies to every target without an override.
+    default: Level,
+    // target -> level overrides, e.g. ("day20", Trace).
+    targets: Vec<(String, Level)>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Self {
+            default: Level::Warn,
+            targets: vec![],
+        }
+    }
+}
+
+impl Filter {
+    pub fn with_verbosity(mut self, n: usize) -> Self {
+        self.default = self.default.max(Level::from_verbosity(n));
+        self
+    }
+
+    // Adds the directives in a --trace spec. A spec is a comma separated list of:
+    //   day20         -> trace everything for the day20 target
+    //   day17=debug   -> day17 up to debug
+    //   debug         -> every target up to debug
+    pub fn add_spec(&mut self, spec: &str) -> Result<(), String> {
+        for directive in spec.split(',').map(|d| d.trim()).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((target, level_str)) => {
+                    let level = Level::from_str(level_str).ok_or_else(|| {
+                        format!("unknown trace level '{}' in '{}'", level_str, directive)
+                    })?;
+                    self.set_target(target, level);
+                }
+                None => match Level::from_str(directive) {
+                    Some(level) => self.default = level,
+                    None => self.set_target(directive, Level::Trace),
+                },
+            }
+        }
+        Ok(())
+    }
+
+    fn set_target(&mut self, target: &str, level: Level) {
+        match self.targets.iter_mut().find(|(t, _)| t == target) {
+            Some(existing) => existing.1 = level,
+            None => self.targets.push((target.to_string(), level)),
+        }
+    }
+
+    pub fn level_for(&self, target: &str) -> Level {
+        self.targets
+            .iter()
+            .find(|(t, _)| t == target)
+            .map(|(_, level)| *level)
+            .unwrap_or(self.default)
+    }
+
+    pub fn enabled(&self, target: &str, level: Level) -> bool {
+        level <= self.level_for(target)
+    }
+
+    // The most verbose level enabled for any target.
+    fn max_level(&self) -> Level {
+        self.targets
+            .iter()
+            .map(|(_, level)| *level)
+            .fold(self.default, Level::max)
+    }
+}
+
+static FILTER: OnceLock<Filter> = OnceLock::new();
+// Cheap pre-check so disabled trace! calls in hot loops don't have to look at the targets.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+
+// Installs the filter. Only the first call has any effect.
+pub fn init(filter: Filter) {
+    let max_level = filter.max_level();
+    if FILTER.set(filter).is_ok() {
+        MAX_LEVEL.store(max_level as u8, Ordering::Relaxed);
+    }
+}
+
+// Parses -v/-vv/-vvv/--verbose and --trace <spec> out of the process arguments, installs the
+// resulting filter and returns the arguments it didn't recognise.
+pub fn init_from_args() -> Vec<String> {
+    match parse_args(std::env::args().skip(1)) {
+        Ok((filter, rest)) => {
+            init(filter);
+            rest
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(2);
+        }
+    }
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<(Filter, Vec<String>), String> {
+    let mut filter = Filter::default();
+    let mut verbosity = 0;
+    let mut rest = vec![];
+
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--verbose" => verbosity += 1,
+            "--trace" => {
+                let spec = args
+                    .next()
+                    .ok_or("--trace needs a spec, e.g. day20=debug")?;
+                filter.add_spec(&spec)?;
+            }
+            a if a.starts_with("--trace=") => filter.add_spec(&a["--trace=".len()..])?,
+            a if a.len() > 1 && a.starts_with('-') && a[1..].chars().all(|c| c == 'v') => {
+                verbosity += a.len() - 1
+            }
+            _ => rest.push(arg),
+        }
+    }
+
+    Ok((filter.with_verbosity(verbosity), rest))
+}
+
+pub fn enabled(target: &str, level: Level) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    match FILTER.get() {
+        Some(filter) => filter.enabled(target, level),
+        None => level <= Level::Warn,
+    }
+}
+
+pub fn emit(target: &str, level: Level, args: fmt::Arguments) {
+    eprintln!("[{} {}] {}", target, level, args);
+}
+
+// For output that isn't a log line, like a grid: draws it to stderr if the target is enabled
+// at the level. The renderers write to any fmt::Write so their output can be tested too.
+pub fn render(target: &str, level: Level, draw: impl FnOnce(&mut String) -> fmt::Result) {
+    if enabled(target, level) {
+        let mut out = String::new();
+        draw(&mut out).expect("writing to a String can't fail");
+        eprint!("{}", out);
+    }
+}
+
+#[macro_export]
+macro_rules! event {
+    ($level:expr, $target:expr, $($arg:tt)+) => {
+        if $crate::trace::enabled($target, $level) {
+            $crate::trace::emit($target, $level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($target:expr, $($arg:tt)+) => {
+        $crate::event!($crate::trace::Level::Error, $target, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($target:expr, $($arg:tt)+) => {
+        $crate::event!($crate::trace::Level::Warn, $target, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($target:expr, $($arg:tt)+) => {
+        $crate::event!($crate::trace::Level::Info, $target, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($target:expr, $($arg:tt)+) => {
+        $crate::event!($crate::trace::Level::Debug, $target, $($arg)+)
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($target:expr, $($arg:tt)+) => {
+        $crate::event!($crate::trace::Level::Trace, $target, $($arg)+)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<(Filter, Vec<String>), String> {
+        parse_args(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let (filter, rest) = parse(&["--trace", "day20", "input.txt", "-vv"]).unwrap();
+        assert_eq!(rest, vec!["input.txt".to_string()]);
+        assert!(filter.enabled("day20", Level::Trace));
+        assert!(filter.enabled("day17", Level::Debug));
+        assert!(!filter.enabled("day17", Level::Trace));
+
+        let (filter, _) = parse(&["--trace=day17=debug,day20=info"]).unwrap();
+        assert!(filter.enabled("day17", Level::Debug));
+        assert!(!filter.enabled("day17", Level::Trace));
+        assert!(filter.enabled("day20", Level::Info));
+        assert!(!filter.enabled("day20", Level::Debug));
+        assert!(!filter.enabled("day01", Level::Info));
+        assert!(filter.enabled("day01", Level::Warn));
+        assert_eq!(filter.max_level(), Level::Debug);
+
+        let (filter, _) = parse(&["--trace", "debug"]).unwrap();
+        assert!(filter.enabled("day05", Level::Debug));
+
+        assert!(parse(&["--trace"]).is_err());
+        assert!(parse(&["--trace", "day17=loud"]).is_err());
+    }
+}
@@ -0,0 +1,608 @@
+// A dashboard for running and inspecting the days of a year:
+//
+//   cargo run --release --features tui --bin aoc -- tui --year 2023
+//
+// Lists every day with its last answers, how long they took and whether the input holds up to
+// the day's assumptions. Pick a day, point it at another input, run either part and scroll
+// through what the solver wrote to stderr, its trace output and any grids it printed.
+//
+// Like watch, every run is an `aoc run` child, which is what lets us capture that output, time
+// the part on its own and stop a solver that takes too long. The child's --stats line ends up in
+// the log, with allocations if aoc was built with count-allocs. Answers saved by earlier runs
+// show up as cached until the day is run again.
+//
+// Without a terminal on stdout there is nothing to draw on, so it prints the cached answers as
+// TSV instead.
+use std::{
+    collections::VecDeque,
+    env, fmt,
+    io::{self, BufRead, BufReader, IsTerminal, Read, Write},
+    path::PathBuf,
+    process::{Child, Command, ExitStatus, Stdio},
+    sync::mpsc,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::{Attribute, Print, SetAttribute},
+    terminal::{self, ClearType},
+};
+
+use crate::{
+    cache::Cache,
+    output::{self, DayAnswers, Format, Printer},
+    runner::{self, Answer, Solution},
+    years::Year,
+};
+
+// How often the screen is redrawn.
+const TICK: Duration = Duration::from_millis(100);
+// How often a running child is looked at, which is how close its time gets to the real one.
+const POLL: Duration = Duration::from_millis(5);
+// A chatty solver can write millions of lines, only the last ones are kept.
+const MAX_LOG: usize = 10_000;
+const ANSWER_WIDTH: usize = 20;
+
+pub struct Config {
+    pub year: &'static Year,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum Task {
+    Part(u8),
+    Check,
+}
+
+impl fmt::Display for Task {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Task::Part(part) => write!(f, "part {}", part),
+            Task::Check => f.write_str("check-input"),
+        }
+    }
+}
+
+// What we know about one day.
+struct Day {
+    solution: &'static Solution,
+    input: PathBuf,
+    // The answer to each part, or why the last run didn't give one.
+    answers: [Option<Result<String, String>>; 2],
+    // None for an answer that came from the cache.
+    timings: [Option<Duration>; 2],
+    // Whether the last check passed, and its summary.
+    check: Option<(bool, String)>,
+    log: VecDeque<String>,
+}
+
+impl Day {
+    fn new(solution: &'static Solution, input: PathBuf, cache: &Cache) -> Self {
+        let mut day = Self {
+            solution,
+            input,
+            answers: [None, None],
+            timings: [None, None],
+            check: None,
+            log: VecDeque::new(),
+        };
+        day.load_cached(cache);
+        day
+    }
+
+    fn load_cached(&mut self, cache: &Cache) {
+        self.timings = [None, None];
+        self.check = None;
+        let input = runner::load_input(&self.input).ok();
+        for part in 1..=2 {
+            self.answers[part as usize - 1] = input
+                .as_ref()
+                .and_then(|input| cache.cached(self.solution, part, input))
+                .map(|answer| Ok(answer.to_string()));
+        }
+    }
+
+    fn log(&mut self, line: &str) {
+        if self.log.len() == MAX_LOG {
+            self.log.pop_front();
+        }
+        self.log.push_back(clean(line));
+    }
+}
+
+// A child working on a task. Its output is read on other threads so a full pipe never blocks
+// it.
+struct Job {
+    day: usize,
+    task: Task,
+    child: Child,
+    started: Instant,
+    stdout: mpsc::Receiver<String>,
+    stderr: mpsc::Receiver<String>,
+    // How it exited and when.
+    exited: Option<(ExitStatus, Duration)>,
+}
+
+enum Mode {
+    Normal,
+    // Editing the selected day's input path.
+    Input(String),
+}
+
+struct App {
+    year: u16,
+    exe: PathBuf,
+    cache: Cache,
+    days: Vec<Day>,
+    selected: usize,
+    // How many lines up from the end of the log we are, 0 follows new output.
+    scroll: usize,
+    verbosity: usize,
+    queue: VecDeque<(usize, Task)>,
+    job: Option<Job>,
+    mode: Mode,
+    message: String,
+}
+
+pub fn tui(config: &Config) -> Result<(), String> {
+    let cache = Cache::default();
+    let days: Vec<Day> = config
+        .year
+        .solutions
+        .iter()
+        .map(|s| Day::new(s, runner::input_path(s.year, s.day), &cache))
+        .collect();
+
+    if !io::stdout().is_terminal() {
+        eprintln!("stdout is not a terminal, printing the cached answers instead");
+        let mut printer = Printer::new(Format::Tsv, true);
+        for day in &days {
+            let [part1, part2] = day.answers.clone().map(|a| a?.ok().map(Answer::from));
+            printer.print(&DayAnswers {
+                year: day.solution.year,
+                day: day.solution.day,
+                part1,
+                part2,
+            });
+        }
+        return Ok(());
+    }
+
+    let mut app = App {
+        year: config.year.year,
+        exe: env::current_exe().map_err(|e| e.to_string())?,
+        cache,
+        days,
+        selected: 0,
+        scroll: 0,
+        verbosity: 0,
+        queue: VecDeque::new(),
+        job: None,
+        mode: Mode::Normal,
+        message: String::new(),
+    };
+    let result = Screen::enter().and_then(|_screen| app.run());
+    app.stop();
+    result.map_err(|e| e.to_string())
+}
+
+// Raw mode on the alternate screen for as long as it lives, so the terminal is put back
+// however we leave, panics included.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        let screen = Screen;
+        execute!(
+            io::stdout(),
+            terminal::EnterAlternateScreen,
+            cursor::Hide,
+            terminal::Clear(ClearType::All)
+        )?;
+        Ok(screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+impl App {
+    fn run(&mut self) -> io::Result<()> {
+        let mut stdout = io::stdout();
+        let mut drawn: Option<Instant> = None;
+        loop {
+            let changed = self.poll();
+            let (width, height) = terminal::size()?;
+            if changed || drawn.is_none_or(|at| at.elapsed() >= TICK) {
+                self.draw(&mut stdout, width as usize, height as usize)?;
+                drawn = Some(Instant::now());
+            }
+            if !event::poll(if self.job.is_some() { POLL } else { TICK })? {
+                continue;
+            }
+            drawn = None;
+            let quit = match event::read()? {
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    !self.key(key, height as usize)
+                }
+                Event::Resize(..) => {
+                    queue!(stdout, terminal::Clear(ClearType::All))?;
+                    false
+                }
+                _ => false,
+            };
+            if quit {
+                return Ok(());
+            }
+        }
+    }
+
+    // Handles a key press, returns false to quit.
+    fn key(&mut self, key: KeyEvent, height: usize) -> bool {
+        if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            return false;
+        }
+        if let Mode::Input(path) = &mut self.mode {
+            match key.code {
+                KeyCode::Char(c) => path.push(c),
+                KeyCode::Backspace => {
+                    path.pop();
+                }
+                KeyCode::Enter => {
+                    let path = PathBuf::from(path.as_str());
+                    if path.is_file() {
+                        let day = &mut self.days[self.selected];
+                        day.input = path;
+                        day.load_cached(&self.cache);
+                        self.message.clear();
+                    } else {
+                        self.message = format!("no such file: {}", path.display());
+                    }
+                    self.mode = Mode::Normal;
+                }
+                KeyCode::Esc => self.mode = Mode::Normal,
+                _ => {}
+            }
+            return true;
+        }
+
+        let page = (height / 2).max(1);
+        let log_len = self.days[self.selected].log.len();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Up | KeyCode::Char('k') => self.select(self.selected.saturating_sub(1)),
+            KeyCode::Down | KeyCode::Char('j') => self.select(self.selected + 1),
+            KeyCode::Enter | KeyCode::Char('r') => self.enqueue_day(self.selected),
+            KeyCode::Char('1') => self.queue.push_back((self.selected, Task::Part(1))),
+            KeyCode::Char('2') if self.days[self.selected].solution.part2.is_some() => {
+                self.queue.push_back((self.selected, Task::Part(2)))
+            }
+            KeyCode::Char('c') => self.queue.push_back((self.selected, Task::Check)),
+            KeyCode::Char('a') => (0..self.days.len()).for_each(|i| self.enqueue_day(i)),
+            KeyCode::Char('i') => {
+                let input = &self.days[self.selected].input;
+                self.mode = Mode::Input(input.to_string_lossy().into_owned());
+            }
+            KeyCode::Char('v') => self.verbosity = (self.verbosity + 1) % 4,
+            KeyCode::Char('x') => self.stop(),
+            KeyCode::PageUp => self.scroll = (self.scroll + page).min(log_len),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_sub(page),
+            KeyCode::Home => self.scroll = log_len,
+            KeyCode::End => self.scroll = 0,
+            _ => {}
+        }
+        true
+    }
+
+    fn select(&mut self, day: usize) {
+        self.selected = day.min(self.days.len() - 1);
+        self.scroll = 0;
+    }
+
+    fn enqueue_day(&mut self, day: usize) {
+        self.queue.push_back((day, Task::Part(1)));
+        if self.days[day].solution.part2.is_some() {
+            self.queue.push_back((day, Task::Part(2)));
+        }
+    }
+
+    // Drops whatever is queued and kills the running child, if any.
+    fn stop(&mut self) {
+        self.queue.clear();
+        if let Some(job) = &mut self.job {
+            let _ = job.child.kill();
+            self.days[job.day].log(&format!("-- {} stopped", job.task));
+        }
+    }
+
+    // Starts the next task once the last one is done, and collects the output of the running
+    // one. Returns whether a task started or finished.
+    fn poll(&mut self) -> bool {
+        let mut changed = false;
+        if self.job.is_none() {
+            if let Some((day, task)) = self.queue.pop_front() {
+                match self.start(day, task) {
+                    Ok(job) => self.job = Some(job),
+                    Err(e) => self.message = format!("could not run {}: {}", self.exe.display(), e),
+                }
+                changed = true;
+            }
+        }
+        let Some(job) = &mut self.job else {
+            return changed;
+        };
+
+        let day = &mut self.days[job.day];
+        let stderr_open = loop {
+            match job.stderr.try_recv() {
+                Ok(line) => day.log(&line),
+                Err(mpsc::TryRecvError::Empty) => break true,
+                Err(mpsc::TryRecvError::Disconnected) => break false,
+            }
+        };
+        if job.exited.is_none() {
+            job.exited = job.child.try_wait().ok().flatten().map(|status| {
+                let elapsed = job.started.elapsed();
+                (status, elapsed)
+            });
+        }
+        // Done once it has exited and all it wrote has been read.
+        let (Some((status, elapsed)), false) = (job.exited, stderr_open) else {
+            return changed;
+        };
+        let stdout = job.stdout.recv().unwrap_or_default();
+
+        match job.task {
+            Task::Part(part) => {
+                let i = part as usize - 1;
+                let answers = output::parse_tsv(&stdout).ok().and_then(|mut a| a.pop());
+                let answer = answers.and_then(|a| if part == 1 { a.part1 } else { a.part2 });
+                day.timings[i] = Some(elapsed);
+                match answer {
+                    Some(answer) if status.success() => {
+                        day.answers[i] = Some(Ok(answer.to_string()));
+                        day.log(&format!("-- part {}: {} in {:.1?}", part, answer, elapsed));
+                    }
+                    _ => {
+                        day.answers[i] = Some(Err(match status.code() {
+                            Some(code) => format!("failed ({})", code),
+                            None => "killed".to_string(),
+                        }));
+                        day.log(&format!("-- part {} failed with {}", part, status));
+                    }
+                }
+            }
+            Task::Check => {
+                // The report goes to stdout, its first line says how many assumptions hold.
+                for line in stdout.lines() {
+                    day.log(line);
+                }
+                let summary = stdout.lines().next().map(check_summary);
+                day.check = Some((status.success(), summary.unwrap_or("no report").to_string()));
+            }
+        }
+        self.job = None;
+        true
+    }
+
+    fn start(&mut self, day: usize, task: Task) -> io::Result<Job> {
+        let d = &mut self.days[day];
+        let mut command = Command::new(&self.exe);
+        match task {
+            Task::Part(part) => command.args([
+                "run",
+                &d.solution.day.to_string(),
+                "--no-cache",
+                "--format",
+                "tsv",
+                "--part",
+                &part.to_string(),
+                "--stats",
+            ]),
+            Task::Check => command.args(["check-input", &d.solution.day.to_string()]),
+        };
+        command
+            .args(["--year", &self.year.to_string(), "--input"])
+            .arg(&d.input);
+        if self.verbosity > 0 {
+            command.arg(format!("-{}", "v".repeat(self.verbosity)));
+        }
+        let mut child = command
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        d.log(&format!("-- {} of {}", task, d.input.display()));
+
+        let (stdout_sender, stdout) = mpsc::channel();
+        let mut pipe = child.stdout.take().unwrap();
+        thread::spawn(move || {
+            let mut out = String::new();
+            let _ = pipe.read_to_string(&mut out);
+            let _ = stdout_sender.send(out);
+        });
+        let (stderr_sender, stderr) = mpsc::channel();
+        let pipe = BufReader::new(child.stderr.take().unwrap());
+        thread::spawn(move || {
+            for line in pipe.lines().map_while(Result::ok) {
+                if stderr_sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(Job {
+            day,
+            task,
+            child,
+            started: Instant::now(),
+            stdout,
+            stderr,
+            exited: None,
+        })
+    }
+
+    fn draw(&self, out: &mut impl Write, width: usize, height: usize) -> io::Result<()> {
+        for (y, (line, highlight)) in self.render(height).iter().enumerate() {
+            queue!(out, cursor::MoveTo(0, y as u16))?;
+            if *highlight {
+                queue!(out, SetAttribute(Attribute::Reverse))?;
+            }
+            queue!(out, Print(fit(line, width)))?;
+            if *highlight {
+                queue!(out, SetAttribute(Attribute::Reset))?;
+            }
+        }
+        out.flush()
+    }
+
+    // The screen as lines of text, each with whether it is highlighted: a title, the days,
+    // the selected day's log and a line of help at the bottom.
+    fn render(&self, height: usize) -> Vec<(String, bool)> {
+        // Everything but the title, the table header, the log header and the help line.
+        let available = height.saturating_sub(4);
+        let list_rows = self.days.len().min(available - available / 3);
+        let log_rows = available - list_rows;
+        let mut lines = vec![];
+
+        let status = match &self.job {
+            Some(job) => format!(
+                "day {:02} {} running for {:.1?}, {} queued",
+                self.days[job.day].solution.day,
+                job.task,
+                job.started.elapsed(),
+                self.queue.len()
+            ),
+            None => self.message.clone(),
+        };
+        lines.push((format!(" aoc {}   {}", self.year, status), false));
+        lines.push((
+            format!(
+                "  {:<4} {:<w$} {:>9}  {:<w$} {:>9}  input",
+                "day",
+                "part1",
+                "time",
+                "part2",
+                "time",
+                w = ANSWER_WIDTH
+            ),
+            false,
+        ));
+        let top = (self.selected + 1).saturating_sub(list_rows);
+        for (i, day) in self.days.iter().enumerate().skip(top).take(list_rows) {
+            lines.push((self.row(i, day), i == self.selected));
+        }
+
+        let day = &self.days[self.selected];
+        let mut title = format!("── day {:02}: {} ", day.solution.day, day.input.display());
+        if self.verbosity > 0 {
+            title += &format!("-{} ", "v".repeat(self.verbosity));
+        }
+        lines.push((format!("{:─<200}", title), false));
+        let end = day.log.len() - self.scroll.min(day.log.len());
+        let start = end.saturating_sub(log_rows);
+        for line in day.log.range(start..end) {
+            lines.push((line.clone(), false));
+        }
+        lines.resize(height.saturating_sub(1), (String::new(), false));
+
+        let footer = match &self.mode {
+            Mode::Input(path) => format!(" input: {}_   (enter to use, esc to cancel)", path),
+            Mode::Normal => " ↑↓ day  r run  1/2 part  c check  a all  i input  v verbose  \
+                              pgup/pgdn scroll  x stop  q quit"
+                .to_string(),
+        };
+        lines.push((footer, true));
+        lines.truncate(height);
+        lines
+    }
+
+    fn row(&self, i: usize, day: &Day) -> String {
+        let cell = |part: u8| {
+            let p = part as usize - 1;
+            let task = Task::Part(part);
+            if self
+                .job
+                .as_ref()
+                .is_some_and(|j| j.day == i && j.task == task)
+            {
+                ("running".to_string(), String::new())
+            } else if self.queue.contains(&(i, task)) {
+                ("queued".to_string(), String::new())
+            } else if part == 2 && day.solution.part2.is_none() {
+                ("-".to_string(), String::new())
+            } else {
+                let time = match (&day.answers[p], day.timings[p]) {
+                    (None, _) => String::new(),
+                    (Some(_), Some(time)) => format!("{:.1?}", time),
+                    (Some(_), None) => "cached".to_string(),
+                };
+                let answer = match &day.answers[p] {
+                    Some(Ok(answer)) | Some(Err(answer)) => answer.clone(),
+                    None => String::new(),
+                };
+                (answer, time)
+            }
+        };
+        let (part1, time1) = cell(1);
+        let (part2, time2) = cell(2);
+        let check = match &day.check {
+            None => String::new(),
+            Some((true, summary)) => format!("ok: {}", summary),
+            Some((false, summary)) => format!("FAIL: {}", summary),
+        };
+        format!(
+            "  {:<4} {:<w$} {:>9}  {:<w$} {:>9}  {}",
+            format!("{:02}", day.solution.day),
+            fit(&part1, ANSWER_WIDTH),
+            time1,
+            fit(&part2, ANSWER_WIDTH),
+            time2,
+            check,
+            w = ANSWER_WIDTH
+        )
+    }
+}
+
+// "day 03: 2/3 assumptions hold" -> "2/3 assumptions hold"
+fn check_summary(line: &str) -> &str {
+    line.split_once(": ").map_or(line, |(_, summary)| summary)
+}
+
+// Cut or padded to exactly width characters.
+fn fit(s: &str, width: usize) -> String {
+    format!("{:<width$.width$}", s, width = width)
+}
+
+// Tabs and control characters would throw the layout off.
+fn clean(line: &str) -> String {
+    line.replace('\t', "    ")
+        .chars()
+        .filter(|c| !c.is_control())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_text() {
+        assert_eq!(fit("55123", 3), "551");
+        assert_eq!(fit("55", 4), "55  ");
+        assert_eq!(clean("a\tb\x1b[0m"), "a    b[0m");
+        assert_eq!(
+            check_summary("day 03: 2/3 assumptions hold"),
+            "2/3 assumptions hold"
+        );
+        assert_eq!(check_summary("no report"), "no report");
+    }
+}
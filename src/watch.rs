@@ -0,0 +1,238 @@
+// Re-runs a day whenever its input or the source changes, for iterating on a solution:
+//
+//   cargo run --release --features watch --bin aoc -- watch 17 --example day17.txt
+//
+// Source changes need a rebuild, so the solver isn't run in this process. Every change rebuilds
+// the aoc binary if needed and runs it as a child for each input, which also means a solver
+// that panics or never finishes doesn't take the watcher down with it.
+use std::{
+    collections::HashMap,
+    env, fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    sync::mpsc,
+    time::{Duration, Instant},
+};
+
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use crate::{debug, output};
+
+// Editors tend to write a file in several steps, wait for them to settle before running.
+const SETTLE: Duration = Duration::from_millis(100);
+
+pub struct Config {
+    pub year: u16,
+    pub day: u8,
+    pub input: PathBuf,
+    // An extra input to run, usually the example from the puzzle text.
+    pub example: Option<PathBuf>,
+}
+
+struct Run {
+    part1: String,
+    part2: Option<String>,
+    elapsed: Duration,
+}
+
+pub fn watch(config: &Config) -> Result<(), String> {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    // Looked up before the first rebuild replaces the file, after which Linux reports the path
+    // of the running binary as deleted.
+    let exe = env::current_exe().map_err(|e| e.to_string())?;
+    let mut inputs = vec![("input", absolute(&config.input)?)];
+    if let Some(example) = &config.example {
+        inputs.push(("example", absolute(example)?));
+    }
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender).map_err(|e| e.to_string())?;
+    // Directories rather than the files themselves, since editors often save by replacing the
+    // file, which would end a watch on it.
+    let src = root.join("src");
+    watch_dir(&mut watcher, &src, RecursiveMode::Recursive)?;
+    for (_, path) in &inputs {
+        let dir = path.parent().unwrap();
+        if !dir.starts_with(&src) {
+            watch_dir(&mut watcher, dir, RecursiveMode::NonRecursive)?;
+        }
+    }
+
+    let mut previous: HashMap<&str, Run> = HashMap::new();
+    let mut rebuild = true;
+    loop {
+        if !rebuild || build(root) {
+            for (name, path) in &inputs {
+                match run(&exe, config.year, config.day, path) {
+                    Ok(run) => {
+                        report(name, path, &run, previous.get(name));
+                        previous.insert(name, run);
+                    }
+                    Err(e) => eprintln!("{} ({}): {}", name, path.display(), e),
+                }
+            }
+        }
+        eprintln!("watching for changes...");
+
+        // Wait for something relevant to change, then for the changes to settle.
+        let mut changed: Vec<PathBuf> = vec![];
+        while !changed
+            .iter()
+            .any(|p| is_source(p) || inputs.iter().any(|(_, i)| i == p))
+        {
+            changed = receive(&receiver, None)?;
+        }
+        while let Ok(more) = receive(&receiver, Some(SETTLE)) {
+            changed.extend(more);
+        }
+        debug!("watch", "changed: {:?}", changed);
+        rebuild = changed.iter().any(|p| is_source(p));
+    }
+}
+
+fn watch_dir(watcher: &mut impl Watcher, dir: &Path, mode: RecursiveMode) -> Result<(), String> {
+    watcher
+        .watch(dir, mode)
+        .map_err(|e| format!("could not watch {}: {}", dir.display(), e))
+}
+
+// The paths touched by the next change event, waiting at most `timeout`.
+fn receive(
+    receiver: &mpsc::Receiver<notify::Result<notify::Event>>,
+    timeout: Option<Duration>,
+) -> Result<Vec<PathBuf>, String> {
+    let event = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout).map_err(|e| e.to_string())?,
+        None => receiver.recv().map_err(|e| e.to_string())?,
+    };
+    match event {
+        Ok(event) if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_)) => {
+            Ok(event.paths)
+        }
+        Ok(_) => Ok(vec![]),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn is_source(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "rs")
+}
+
+fn absolute(path: &Path) -> Result<PathBuf, String> {
+    fs::canonicalize(path).map_err(|e| format!("could not find {}: {}", path.display(), e))
+}
+
+// Rebuilds the binary we are running, with the same profile and features. Compiler errors go
+// straight to stderr.
+fn build(root: &Path) -> bool {
+    let mut command = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_string()));
+    command.current_dir(root).args(["build", "--bin", "aoc"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+    command.args(["--features", &enabled_features().join(",")]);
+
+    match command.status() {
+        Ok(status) if status.success() => true,
+        Ok(_) => {
+            eprintln!("build failed");
+            false
+        }
+        Err(e) => {
+            eprintln!("could not run cargo: {}", e);
+            false
+        }
+    }
+}
+
+// Every optional feature in Cargo.toml this binary was built with, watch always among them.
+fn enabled_features() -> Vec<&'static str> {
+    [
+        ("checked", cfg!(feature = "checked")),
+        ("count-allocs", cfg!(feature = "count-allocs")),
+        ("serve", cfg!(feature = "serve")),
+        ("tui", cfg!(feature = "tui")),
+        ("watch", cfg!(feature = "watch")),
+        ("z3", cfg!(feature = "z3")),
+    ]
+    .into_iter()
+    .filter_map(|(feature, enabled)| enabled.then_some(feature))
+    .collect()
+}
+
+fn run(exe: &Path, year: u16, day: u8, input: &Path) -> Result<Run, String> {
+    let start = Instant::now();
+    let output = Command::new(exe)
+        .args([
+            "run",
+            &day.to_string(),
+            "--year",
+            &year.to_string(),
+            "--no-cache",
+            "--format",
+            "tsv",
+            "--input",
+        ])
+        .arg(input)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| e.to_string())?;
+    let elapsed = start.elapsed();
+    if !output.status.success() {
+        return Err(format!("failed with {}", output.status));
+    }
+
+    let answers = output::parse_tsv(&String::from_utf8_lossy(&output.stdout))?
+        .pop()
+        .ok_or("no answers")?;
+    Ok(Run {
+        part1: answers.part1.ok_or("no answer for part 1")?.to_string(),
+        part2: answers.part2.map(|a| a.to_string()),
+        elapsed,
+    })
+}
+
+fn report(name: &str, path: &Path, run: &Run, previous: Option<&Run>) {
+    println!("{} ({}) in {:.1?}", name, path.display(), run.elapsed);
+    println!(
+        "  part1: {}{}",
+        run.part1,
+        change(previous.map(|p| p.part1.as_str()), &run.part1)
+    );
+    if let Some(part2) = &run.part2 {
+        println!(
+            "  part2: {}{}",
+            part2,
+            change(previous.and_then(|p| p.part2.as_deref()), part2)
+        );
+    }
+}
+
+// How an answer compares to the previous run's.
+fn change(previous: Option<&str>, current: &str) -> String {
+    match previous {
+        None => String::new(),
+        Some(previous) if previous == current => "  (unchanged)".to_string(),
+        Some(previous) => format!("  (was {})", previous),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_change() {
+        assert_eq!(change(None, "42"), "");
+        assert_eq!(change(Some("42"), "42"), "  (unchanged)");
+        assert_eq!(change(Some("41"), "42"), "  (was 41)");
+    }
+
+    #[test]
+    fn test_enabled_features() {
+        let features = enabled_features();
+        assert!(features.contains(&"watch"));
+        assert_eq!(features.contains(&"serve"), cfg!(feature = "serve"));
+        assert_eq!(features.contains(&"checked"), cfg!(feature = "checked"));
+    }
+}
@@ -0,0 +1,780 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    io::{self, BufRead},
+};
+
+use crate::{
+    arith, debug,
+    runner::{self, Answer},
+    stream,
+    trace::{self, Level},
+    warn,
+};
+
+pub fn part1(input: &str) -> Answer {
+    stream::solve_str(part1_streaming, input)
+}
+
+pub fn part2(input: &str) -> Answer {
+    stream::solve_str(part2_streaming, input)
+}
+
+pub fn part1_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    Ok(calibration_sum(input, &Vocabulary::digits())?.into())
+}
+
+// The day's rule setting switches to the extended calibration, e.g. AOC_2023_DAY01_RULE=sum.
+pub fn part2_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let vocabulary = Vocabulary::configured()?;
+    match CalibrationRule::configured()? {
+        Some(_) if vocabulary != Vocabulary::english() => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the extended calibration reads numbers, not a vocabulary",
+        )),
+        Some(rule) => Ok(extended_calibration_sum(input, rule)?.into()),
+        None => Ok(calibration_sum(input, &vocabulary)?.into()),
+    }
+}
+
+// The sum of every line's calibration value, reading the digits with the given vocabulary. A
+// line without a digit is warned about and counts as 0. At debug level every line is
+// explained, to see what went wrong with an answer:
+//
+//   aoc run 1 --part 2 --no-cache --trace day01=debug
+pub fn calibration_sum(input: &mut dyn BufRead, vocabulary: &Vocabulary) -> io::Result<i64> {
+    if trace::enabled("day01", Level::Debug) {
+        return explain(input, vocabulary, |explanation| {
+            debug!("day01", "{}", explanation)
+        });
+    }
+
+    let matcher = Matcher::new(vocabulary);
+    let mut lines = stream::lines(input);
+    let mut sum = 0;
+    let mut number = 0;
+    while let Some(line) = lines.next_bytes()? {
+        number += 1;
+        match matcher.first_and_last(line) {
+            Some((first, last)) => sum += (first.digit * 10 + last.digit) as i64,
+            None => warn!("day01", "line {}: {}", number, LineError::NoDigit),
+        }
+    }
+    Ok(sum)
+}
+
+// Like calibration_sum, handing every line's explanation to report as it goes.
+pub fn explain(
+    input: &mut dyn BufRead,
+    vocabulary: &Vocabulary,
+    mut report: impl FnMut(&Explanation),
+) -> io::Result<i64> {
+    let matcher = Matcher::new(vocabulary);
+    let mut lines = stream::lines(input);
+    let mut sum = 0;
+    let mut number = 0;
+    while let Some(line) = lines.next_bytes()? {
+        number += 1;
+        let explanation = matcher.explain(number, line);
+        report(&explanation);
+        sum += explanation.value().unwrap_or(0);
+    }
+    Ok(sum)
+}
+
+// What was made of one line: every token found in it, overlapping ones included, and the two
+// that give its value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Explanation {
+    // Counting from 1.
+    pub line: usize,
+    pub text: String,
+    pub matches: Vec<Match>,
+    pub first: Option<Match>,
+    pub last: Option<Match>,
+}
+
+impl Explanation {
+    pub fn value(&self) -> Result<i64, LineError> {
+        match (&self.first, &self.last) {
+            (Some(first), Some(last)) => Ok((first.digit * 10 + last.digit) as i64),
+            _ => Err(LineError::NoDigit),
+        }
+    }
+}
+
+// line 3 "xtwone3four": two@1 one@3 3@6 four@7, first two@1, last four@7, value 24
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {} {:?}: ", self.line, self.text)?;
+        if self.matches.is_empty() {
+            write!(f, "no matches")?;
+        }
+        for (i, m) in self.matches.iter().enumerate() {
+            write!(f, "{}{}", if i > 0 { " " } else { "" }, m)?;
+        }
+        match (&self.first, &self.last, self.value()) {
+            (Some(first), Some(last), Ok(value)) => {
+                write!(f, ", first {}, last {}, value {}", first, last, value)
+            }
+            (_, _, Err(e)) => write!(f, ", {}", e),
+            _ => unreachable!("a value needs a first and last match"),
+        }
+    }
+}
+
+// A token found in a line, at a byte offset.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub token: String,
+    pub digit: u8,
+}
+
+impl fmt::Display for Match {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.token, self.start)
+    }
+}
+
+// Why a line has no calibration value. The codes stay the same, to search logs for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LineError {
+    // Nothing in the vocabulary was found.
+    NoDigit,
+    // The extended calibration found no number.
+    NoNumber,
+    // The extended calibration found a number too big for a u64.
+    TooBig,
+}
+
+impl LineError {
+    pub fn code(self) -> &'static str {
+        match self {
+            Self::NoDigit => "E001",
+            Self::NoNumber => "E002",
+            Self::TooBig => "E003",
+        }
+    }
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            Self::NoDigit => "no digit",
+            Self::NoNumber => "no number",
+            Self::TooBig => "number too big",
+        };
+        write!(f, "error {}: {}", self.code(), message)
+    }
+}
+
+// The tokens that are read as digits, e.g. "7" or "seven". Any text will do as a token, so other
+// languages only need their own vocabulary, which part 2 reads from the day's vocabulary
+// setting, e.g. AOC_2023_DAY01_VOCABULARY=@german.txt:
+//
+//   # one token and its digit per line
+//   eins 1
+//   zwei 2
+//   drei 3
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Vocabulary {
+    tokens: Vec<(String, u8)>,
+}
+
+impl Vocabulary {
+    // Just the digits themselves, as in part 1.
+    pub fn digits() -> Self {
+        Self {
+            tokens: (0..=9).map(|d| (d.to_string(), d)).collect(),
+        }
+    }
+
+    // The digits 1 to 9 and the English words for them, as in part 2. There is no "zero" in the
+    // puzzle, so '0' isn't a digit here.
+    pub fn english() -> Self {
+        const WORDS: [&str; 9] = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        let digits = (1..=9).map(|d| (d.to_string(), d));
+        let words = WORDS.iter().zip(1..).map(|(w, d)| (w.to_string(), d));
+        Self {
+            tokens: digits.chain(words).collect(),
+        }
+    }
+
+    // The vocabulary in the day's vocabulary setting, or English.
+    pub fn configured() -> io::Result<Self> {
+        let invalid = |e| io::Error::new(io::ErrorKind::InvalidInput, e);
+        let setting = runner::setting(2023, 1, "vocabulary").map_err(invalid)?;
+        Self::from_setting(setting.as_deref()).map_err(invalid)
+    }
+
+    // The vocabulary the text of a vocabulary setting gives, English if it isn't set.
+    pub fn from_setting(setting: Option<&str>) -> Result<Self, String> {
+        match setting {
+            Some(text) => Self::parse(text).map_err(|e| format!("vocabulary setting: {}", e)),
+            None => Ok(Self::english()),
+        }
+    }
+
+    pub fn from_map<S: Into<String>>(
+        map: impl IntoIterator<Item = (S, u8)>,
+    ) -> Result<Self, String> {
+        let mut tokens: Vec<(String, u8)> = vec![];
+        for (token, digit) in map {
+            let token = token.into();
+            if token.is_empty() {
+                return Err("empty token".to_string());
+            }
+            if digit > 9 {
+                return Err(format!("'{}' is {}, which isn't a digit", token, digit));
+            }
+            if tokens.iter().any(|(t, _)| *t == token) {
+                return Err(format!("'{}' is in the vocabulary twice", token));
+            }
+            tokens.push((token, digit));
+        }
+        Ok(Self { tokens })
+    }
+
+    // Parses the "token digit" lines of a vocabulary file. Blank lines and lines starting with
+    // '#' are skipped.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut map = vec![];
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let parsed = line
+                .split_once(char::is_whitespace)
+                .and_then(|(token, digit)| Some((token, digit.trim().parse().ok()?)));
+            let Some(entry) = parsed else {
+                return Err(format!(
+                    "line {}: expected 'token digit', got '{}'",
+                    i + 1,
+                    line
+                ));
+            };
+            map.push(entry);
+        }
+        Self::from_map(map)
+    }
+}
+
+// An Aho-Corasick automaton over the bytes of a vocabulary's tokens: a trie of them, with the
+// missing transitions of every state filled in from its failure link, so a line is matched in a
+// single pass without backtracking. Matches may overlap, "twone" has both "two" and "one".
+struct Matcher {
+    // The state after reading a byte is next[state * 256 + byte].
+    next: Vec<u32>,
+    // The (length, digit) of the tokens that end at each state, its own and its suffixes'.
+    outputs: Vec<Vec<(usize, u8)>>,
+}
+
+impl Matcher {
+    fn new(vocabulary: &Vocabulary) -> Self {
+        const NONE: u32 = u32::MAX;
+
+        let mut trie = vec![[NONE; 256]];
+        let mut outputs = vec![vec![]];
+        for (token, digit) in &vocabulary.tokens {
+            let mut state = 0;
+            for &b in token.as_bytes() {
+                if trie[state][b as usize] == NONE {
+                    trie[state][b as usize] = trie.len() as u32;
+                    trie.push([NONE; 256]);
+                    outputs.push(vec![]);
+                }
+                state = trie[state][b as usize] as usize;
+            }
+            outputs[state].push((token.len(), *digit));
+        }
+
+        // Breadth first, so a state's failure link is finished before the state is.
+        let mut next = vec![0; trie.len() * 256];
+        let mut fail = vec![0; trie.len()];
+        let mut queue = VecDeque::from([0]);
+        while let Some(state) = queue.pop_front() {
+            if state != 0 {
+                let inherited = outputs[fail[state]].clone();
+                outputs[state].extend(inherited);
+            }
+            for b in 0..256 {
+                let child = trie[state][b];
+                let fallback = if state == 0 {
+                    0
+                } else {
+                    next[fail[state] * 256 + b]
+                };
+                if child == NONE {
+                    next[state * 256 + b] = fallback;
+                } else {
+                    next[state * 256 + b] = child;
+                    fail[child as usize] = fallback as usize;
+                    queue.push_back(child as usize);
+                }
+            }
+        }
+
+        Self { next, outputs }
+    }
+
+    // Every token in line as it is found, by where it ends, as (start, length, digit).
+    fn matches<'a>(&'a self, line: &'a [u8]) -> impl Iterator<Item = RawMatch> + 'a {
+        line.iter()
+            .enumerate()
+            .scan(0, |state, (i, &b)| {
+                *state = self.next[*state * 256 + b as usize] as usize;
+                Some((i, *state))
+            })
+            .flat_map(|(i, state)| {
+                self.outputs[state]
+                    .iter()
+                    .map(move |&(len, digit)| RawMatch {
+                        start: i + 1 - len,
+                        len,
+                        digit,
+                    })
+            })
+    }
+
+    // The tokens that start first and last in line. When two tokens start at the same place
+    // the longer one counts.
+    fn first_and_last(&self, line: &[u8]) -> Option<(RawMatch, RawMatch)> {
+        let mut first: Option<RawMatch> = None;
+        let mut last: Option<RawMatch> = None;
+        for m in self.matches(line) {
+            if first.is_none_or(|f| m.start < f.start || (m.start == f.start && m.len > f.len)) {
+                first = Some(m);
+            }
+            if last.is_none_or(|l| m.start > l.start || (m.start == l.start && m.len > l.len)) {
+                last = Some(m);
+            }
+        }
+        Some((first?, last?))
+    }
+
+    fn explain(&self, number: usize, line: &[u8]) -> Explanation {
+        let to_match = |m: RawMatch| Match {
+            start: m.start,
+            token: String::from_utf8_lossy(&line[m.start..m.start + m.len]).into_owned(),
+            digit: m.digit,
+        };
+        let mut matches: Vec<RawMatch> = self.matches(line).collect();
+        matches.sort_by_key(|m| (m.start, m.len));
+        let first_and_last = self.first_and_last(line);
+        Explanation {
+            line: number,
+            text: String::from_utf8_lossy(line).into_owned(),
+            matches: matches.into_iter().map(to_match).collect(),
+            first: first_and_last.map(|(first, _)| to_match(first)),
+            last: first_and_last.map(|(_, last)| to_match(last)),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+struct RawMatch {
+    start: usize,
+    len: usize,
+    digit: u8,
+}
+
+// The extended calibration, which reads whole numbers rather than digits: digit runs like "42"
+// and English number words, compounds included, like "twenty-one", "forty two" or "one
+// hundred and five". Words are matched longest first, so "seventeen" is 17 rather than 7.
+pub fn extended_calibration_sum(input: &mut dyn BufRead, rule: CalibrationRule) -> io::Result<u64> {
+    let mut lines = stream::lines(input);
+    let mut sum = 0;
+    let mut number = 0;
+    while let Some(line) = lines.next_str()? {
+        number += 1;
+        let numbers = numbers(line).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}", number, e),
+            )
+        })?;
+        let (Some(&first), Some(&last)) = (numbers.first(), numbers.last()) else {
+            warn!("day01", "line {}: {}", number, LineError::NoNumber);
+            continue;
+        };
+        let value = rule.apply(first, last);
+        debug!(
+            "day01",
+            "line {} {:?}: {:?}, value {}", number, line, numbers, value
+        );
+        sum = arith::add("day01 calibration sum", sum, value);
+    }
+    Ok(sum)
+}
+
+// How the first and last numbers on a line make its calibration value.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum CalibrationRule {
+    // The first digit of the first number and the last digit of the last, like the puzzle's.
+    Digits,
+    // The numbers written one after the other, 12 and 345 make 12345.
+    Concatenated,
+    // The numbers added up.
+    Sum,
+}
+
+impl CalibrationRule {
+    pub fn parse(s: &str) -> Result<Self, String> {
+        match s.trim() {
+            "digits" => Ok(Self::Digits),
+            "concatenated" => Ok(Self::Concatenated),
+            "sum" => Ok(Self::Sum),
+            s => Err(format!(
+                "unknown rule '{}', expected digits, concatenated or sum",
+                s
+            )),
+        }
+    }
+
+    // The rule in the day's rule setting, if there is one.
+    pub fn configured() -> io::Result<Option<Self>> {
+        let invalid = |e| io::Error::new(io::ErrorKind::InvalidInput, e);
+        match runner::setting(2023, 1, "rule").map_err(invalid)? {
+            Some(rule) => Self::parse(&rule)
+                .map(Some)
+                .map_err(|e| invalid(format!("rule setting: {}", e))),
+            None => Ok(None),
+        }
+    }
+
+    pub fn apply(self, first: u64, last: u64) -> u64 {
+        match self {
+            Self::Digits => {
+                let mut leading = first;
+                while leading >= 10 {
+                    leading /= 10;
+                }
+                leading * 10 + last % 10
+            }
+            Self::Concatenated => {
+                let shift = 10u64.pow(last.checked_ilog10().unwrap_or(0) + 1);
+                arith::add(
+                    "day01 concatenated",
+                    arith::mul("day01 concatenated", first, shift),
+                    last,
+                )
+            }
+            Self::Sum => arith::add("day01 sum", first, last),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum NumberWord {
+    // zero to nine
+    Unit(u64),
+    // ten to nineteen
+    Teen(u64),
+    // twenty, thirty.. ninety
+    Tens(u64),
+    Hundred,
+    Thousand,
+    Million,
+}
+
+const NUMBER_WORDS: &[(&str, NumberWord)] = &[
+    ("zero", NumberWord::Unit(0)),
+    ("one", NumberWord::Unit(1)),
+    ("two", NumberWord::Unit(2)),
+    ("three", NumberWord::Unit(3)),
+    ("four", NumberWord::Unit(4)),
+    ("five", NumberWord::Unit(5)),
+    ("six", NumberWord::Unit(6)),
+    ("seven", NumberWord::Unit(7)),
+    ("eight", NumberWord::Unit(8)),
+    ("nine", NumberWord::Unit(9)),
+    ("ten", NumberWord::Teen(10)),
+    ("eleven", NumberWord::Teen(11)),
+    ("twelve", NumberWord::Teen(12)),
+    ("thirteen", NumberWord::Teen(13)),
+    ("fourteen", NumberWord::Teen(14)),
+    ("fifteen", NumberWord::Teen(15)),
+    ("sixteen", NumberWord::Teen(16)),
+    ("seventeen", NumberWord::Teen(17)),
+    ("eighteen", NumberWord::Teen(18)),
+    ("nineteen", NumberWord::Teen(19)),
+    ("twenty", NumberWord::Tens(20)),
+    ("thirty", NumberWord::Tens(30)),
+    ("forty", NumberWord::Tens(40)),
+    ("fifty", NumberWord::Tens(50)),
+    ("sixty", NumberWord::Tens(60)),
+    ("seventy", NumberWord::Tens(70)),
+    ("eighty", NumberWord::Tens(80)),
+    ("ninety", NumberWord::Tens(90)),
+    ("hundred", NumberWord::Hundred),
+    ("thousand", NumberWord::Thousand),
+    ("million", NumberWord::Million),
+];
+
+// The numbers in a line, in order.
+pub fn numbers(line: &str) -> Result<Vec<u64>, LineError> {
+    let mut numbers = vec![];
+    // The number the words so far make, and where its last word ended.
+    let mut compound: Option<(Compound, usize)> = None;
+    let mut i = 0;
+    while i < line.len() {
+        let rest = &line[i..];
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits > 0 {
+            numbers.extend(compound.take().map(|(c, _)| c.value()));
+            let run = &rest[..digits];
+            numbers.push(run.parse().map_err(|_| LineError::TooBig)?);
+            i += digits;
+            continue;
+        }
+        let Some((word, number_word)) = NUMBER_WORDS
+            .iter()
+            .filter(|(word, _)| rest.starts_with(word))
+            .max_by_key(|(word, _)| word.len())
+        else {
+            i += rest.chars().next().unwrap().len_utf8();
+            continue;
+        };
+
+        let continued = match &mut compound {
+            Some((c, end)) => {
+                let and = match line[*end..i].trim_matches(|ch| ch == ' ' || ch == '-') {
+                    "" => Some(false),
+                    "and" if line[*end..i].contains(" and ") => Some(true),
+                    _ => None,
+                };
+                and.is_some_and(|and| c.add(*number_word, and))
+            }
+            None => false,
+        };
+        if !continued {
+            numbers.extend(compound.take().map(|(c, _)| c.value()));
+            compound = Some((Compound::new(*number_word), 0));
+        }
+        i += word.len();
+        compound.as_mut().unwrap().1 = i;
+    }
+    numbers.extend(compound.map(|(c, _)| c.value()));
+    Ok(numbers)
+}
+
+// A number being read a word at a time, e.g. "two thousand three hundred and forty five" is
+// (0, 2), (2000, 0), (2000, 3), (2000, 300), (2000, 340), (2000, 345).
+struct Compound {
+    // The thousands and millions so far.
+    total: u64,
+    // Below a thousand.
+    current: u64,
+    last: NumberWord,
+}
+
+impl Compound {
+    fn new(word: NumberWord) -> Self {
+        let current = match word {
+            NumberWord::Unit(n) | NumberWord::Teen(n) | NumberWord::Tens(n) => n,
+            // A scale on its own, as in "hundred and one", counts as one of it.
+            NumberWord::Hundred => 100,
+            NumberWord::Thousand => 1_000,
+            NumberWord::Million => 1_000_000,
+        };
+        let (total, current) = if current >= 1_000 {
+            (current, 0)
+        } else {
+            (0, current)
+        };
+        Self {
+            total,
+            current,
+            last: word,
+        }
+    }
+
+    // Adds the next word if it carries on the number, after "and" if `and`. Returns whether it
+    // did.
+    fn add(&mut self, word: NumberWord, and: bool) -> bool {
+        use NumberWord::*;
+
+        let after_scale = matches!(self.last, Hundred | Thousand | Million);
+        if and && !after_scale {
+            return false;
+        }
+        let below_hundred = self.current % 100;
+        match (self.last, word) {
+            // "twenty-one"
+            (Tens(_), Unit(n)) if n > 0 && !and => self.current += n,
+            // "one hundred and five", "two thousand twenty", "a million and one"
+            (Hundred | Thousand | Million, Unit(n) | Teen(n) | Tens(n))
+                if n > 0 && below_hundred == 0 =>
+            {
+                self.current += n
+            }
+            // "three hundred"
+            (Unit(n), Hundred) if n > 0 && self.current == n && !and => self.current *= 100,
+            // "forty two thousand"
+            (_, Thousand) if self.current > 0 && self.total.is_multiple_of(1_000_000) && !and => {
+                self.total += self.current * 1_000;
+                self.current = 0;
+            }
+            // "six million"
+            (_, Million) if self.current > 0 && self.total == 0 && !and => {
+                self.total = self.current * 1_000_000;
+                self.current = 0;
+            }
+            _ => return false,
+        }
+        self.last = word;
+        true
+    }
+
+    fn value(&self) -> u64 {
+        self.total + self.current
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn first_and_last(vocabulary: &Vocabulary, line: &str) -> Option<(u8, u8)> {
+        let (first, last) = Matcher::new(vocabulary).first_and_last(line.as_bytes())?;
+        Some((first.digit, last.digit))
+    }
+
+    #[test]
+    fn test_examples() {
+        let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+        assert_eq!(part1(input), 142.into());
+
+        let input = "two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen";
+        assert_eq!(part2(input), 281.into());
+    }
+
+    #[test]
+    fn test_overlapping_words() {
+        let english = Vocabulary::english();
+        assert_eq!(first_and_last(&english, "twone"), Some((2, 1)));
+        assert_eq!(first_and_last(&english, "oneight"), Some((1, 8)));
+        assert_eq!(first_and_last(&english, "xeightwox"), Some((8, 2)));
+        assert_eq!(first_and_last(&english, "sevenine"), Some((7, 9)));
+        assert_eq!(first_and_last(&english, "abc"), None);
+        assert_eq!(first_and_last(&english, "0two0"), Some((2, 2)));
+
+        // The longer token wins where two start together, and a token inside another counts.
+        let vocabulary = Vocabulary::from_map([("ab", 1), ("abcd", 2), ("bc", 3)]).unwrap();
+        assert_eq!(first_and_last(&vocabulary, "abcd"), Some((2, 3)));
+        assert_eq!(first_and_last(&vocabulary, "xabx"), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_vocabulary_file() {
+        let german = Vocabulary::parse(
+            "# Deutsch
+eins 1
+zwei 2
+drei 3
+fünf 5
+
+sieben 7",
+        )
+        .unwrap();
+        let input = "xzweinsx\nfünfundsiebzig\nsiebenxdrei";
+        assert_eq!(
+            calibration_sum(&mut input.as_bytes(), &german).unwrap(),
+            21 + 55 + 73
+        );
+
+        assert!(Vocabulary::parse("eins").is_err());
+        assert!(Vocabulary::parse("eins 10").is_err());
+        assert!(Vocabulary::parse("eins 1\neins 2").is_err());
+    }
+
+    #[test]
+    fn test_vocabulary_setting() {
+        let vocabulary = Vocabulary::from_setting(Some("eins 1\nzwei 2\nsechs 6\n7 7")).unwrap();
+        assert_eq!(first_and_last(&vocabulary, "xzweinsx"), Some((2, 1)));
+        assert_eq!(first_and_last(&vocabulary, "sechsxseven7"), Some((6, 7)));
+        assert_eq!(first_and_last(&vocabulary, "one"), None);
+        assert_eq!(Vocabulary::from_setting(None), Ok(Vocabulary::english()));
+        assert_eq!(
+            Vocabulary::from_setting(Some("eins")),
+            Err("vocabulary setting: line 1: expected 'token digit', got 'eins'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_compound_numbers() {
+        assert_eq!(numbers("twenty-one").unwrap(), [21]);
+        assert_eq!(numbers("xforty twoy").unwrap(), [42]);
+        assert_eq!(numbers("one hundred and five").unwrap(), [105]);
+        assert_eq!(
+            numbers("two thousand three hundred and forty-five").unwrap(),
+            [2345]
+        );
+        assert_eq!(numbers("six million twelve").unwrap(), [6_000_012]);
+        assert_eq!(
+            numbers("ab12twenty-onexyz3seventeen").unwrap(),
+            [12, 21, 3, 17]
+        );
+        // Words that don't make one number between them are separate numbers.
+        assert_eq!(
+            numbers("one two and twenty thirty").unwrap(),
+            [1, 2, 20, 30]
+        );
+        assert_eq!(numbers("twenty one hundred").unwrap(), [21, 100]);
+        assert_eq!(numbers("twone").unwrap(), [2]);
+
+        let input = "a twenty-one b forty two c\n7 one hundred and five";
+        let sum = |rule| extended_calibration_sum(&mut input.as_bytes(), rule).unwrap();
+        assert_eq!(sum(CalibrationRule::Digits), 22 + 75);
+        assert_eq!(sum(CalibrationRule::Concatenated), 2142 + 7105);
+        assert_eq!(sum(CalibrationRule::Sum), 63 + 112);
+
+        assert_eq!(numbers("x99999999999999999999"), Err(LineError::TooBig));
+        let err = extended_calibration_sum(
+            &mut "1\n99999999999999999999".as_bytes(),
+            CalibrationRule::Sum,
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "line 2: error E003: number too big");
+
+        assert_eq!(CalibrationRule::parse("sum"), Ok(CalibrationRule::Sum));
+        assert_eq!(
+            CalibrationRule::parse(" concatenated\n"),
+            Ok(CalibrationRule::Concatenated)
+        );
+        assert!(CalibrationRule::parse("product").is_err());
+    }
+
+    #[test]
+    fn test_explain() {
+        let mut explanations = vec![];
+        let input = "xtwone3four\nabc\n7";
+        let sum = explain(&mut input.as_bytes(), &Vocabulary::english(), |e| {
+            explanations.push(e.to_string())
+        });
+        assert_eq!(sum.unwrap(), 24 + 77);
+        assert_eq!(
+            explanations,
+            [
+                r#"line 1 "xtwone3four": two@1 one@3 3@6 four@7, first two@1, last four@7, value 24"#,
+                r#"line 2 "abc": no matches, error E001: no digit"#,
+                r#"line 3 "7": 7@0, first 7@0, last 7@0, value 77"#,
+            ]
+        );
+        assert_eq!(part1("a1b\nnope\n2"), 33.into());
+    }
+}
@@ -0,0 +1,483 @@
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    fmt,
+    io::{self, BufRead},
+};
+
+use crate::{arith, runner, runner::Answer, stream};
+
+pub fn part1(input: &str) -> Answer {
+    stream::solve_str(part1_streaming, input)
+}
+
+pub fn part2(input: &str) -> Answer {
+    stream::solve_str(part2_streaming, input)
+}
+
+// A colour the bag doesn't have counts as none of it, so a game showing one is impossible.
+pub fn part1_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let bag = Bag::configured()?;
+    let mut games = Games::new(input);
+
+    let mut sum = 0;
+    while let Some(game) = games.next_game()? {
+        if game.is_possible(&bag) {
+            sum += game.id;
+        }
+    }
+    Ok(sum.into())
+}
+
+pub fn part2_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let bag = Bag::configured()?;
+    let mut games = Games::new(input);
+
+    let mut power_sum = 0;
+    while let Some(game) = games.next_game()? {
+        power_sum = arith::add("day02 power sum", power_sum, game.power(&bag));
+    }
+
+    Ok(power_sum.into())
+}
+
+// How many cubes of each colour were shown at once.
+type Pull = BTreeMap<String, i64>;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    pub id: i64,
+    pub pulls: Vec<Pull>,
+}
+
+impl Game {
+    // "Game 3: 8 green, 6 blue; 5 blue, 4 red", in any colours.
+    pub fn parse(line: &str) -> Result<Self, String> {
+        let (game, pulls) = line
+            .split_once(':')
+            .ok_or("expected 'Game <id>: <pulls>'")?;
+        let id = game
+            .strip_prefix("Game ")
+            .ok_or_else(|| format!("expected 'Game <id>', got '{}'", game))?;
+        let id = parse_count(id).ok_or_else(|| format!("bad game id '{}'", id))?;
+
+        let pulls = pulls
+            .split(';')
+            .map(|pull| {
+                let pull = parse_cubes(pull)?;
+                if pull.is_empty() {
+                    return Err("empty pull".to_string());
+                }
+                Ok(pull)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self { id, pulls })
+    }
+
+    // The fewest cubes of each colour the game could have been played with.
+    pub fn minimal_bag(&self) -> Bag {
+        minimal_bag([self])
+    }
+
+    // Whether the game could have been played with the bag. A colour the bag doesn't have
+    // counts as none of it.
+    pub fn is_possible(&self, bag: &Bag) -> bool {
+        self.pulls.iter().all(|pull| bag.is_possible(pull))
+    }
+
+    // The product of the minimal bag's counts. Every colour in the bag counts, so a game that
+    // never shows one of them has no power, and so does every colour the game shows.
+    pub fn power(&self, bag: &Bag) -> i64 {
+        let minimal = self.minimal_bag();
+        let colours: BTreeSet<&str> = bag.colours().chain(minimal.colours()).collect();
+        arith::product("day02 power", colours.iter().map(|c| minimal.count(c)))
+    }
+}
+
+// Every game in an input, for the queries below.
+pub fn read_games(input: &mut dyn BufRead) -> io::Result<Vec<Game>> {
+    let mut games = Games::new(input);
+    let mut all = vec![];
+    while let Some(game) = games.next_game()? {
+        all.push(game);
+    }
+    Ok(all)
+}
+
+// The smallest bag that makes every one of the games possible.
+pub fn minimal_bag<'a>(games: impl IntoIterator<Item = &'a Game>) -> Bag {
+    let mut limits = Pull::new();
+    for pull in games.into_iter().flat_map(|game| &game.pulls) {
+        for (colour, &count) in pull {
+            let limit = limits.entry(colour.clone()).or_insert(0);
+            *limit = (*limit).max(count);
+        }
+    }
+    Bag { limits }
+}
+
+// For each bag, the ids of the games it makes possible. The games' minimal bags are only
+// worked out once, however many bags there are.
+pub fn possible_games(games: &[Game], bags: &[Bag]) -> Vec<Vec<i64>> {
+    let minimal: Vec<(i64, Bag)> = games.iter().map(|g| (g.id, g.minimal_bag())).collect();
+    bags.iter()
+        .map(|bag| {
+            minimal
+                .iter()
+                .filter(|(_, needed)| bag.holds(needed))
+                .map(|(id, _)| *id)
+                .collect()
+        })
+        .collect()
+}
+
+// The Pareto-minimal bags that make at least k of the games possible: each makes k games
+// possible, and taking a cube of any colour out of it would leave fewer.
+//
+// Such a bag has exactly as many of each colour as one of the games needs, or it could lose one.
+// So it's enough to try the counts games need of every colour but the last, and then take the
+// k-th smallest of the last colour among the games those allow. That is n^(colours - 1)
+// candidates for n games, fine for the puzzle's three colours.
+pub fn pareto_minimal_bags(games: &[Game], k: usize) -> Vec<Bag> {
+    let minimal: Vec<Bag> = games.iter().map(Game::minimal_bag).collect();
+    let colours: Vec<String> = minimal_bag(games).limits.into_keys().collect();
+    let needs: Vec<Vec<i64>> = minimal
+        .iter()
+        .map(|bag| colours.iter().map(|c| bag.count(c)).collect())
+        .collect();
+    if k > needs.len() {
+        return vec![];
+    }
+    if k == 0 || colours.is_empty() {
+        return vec![Bag::from_counts(&colours, &vec![0; colours.len()])];
+    }
+
+    let mut candidates = vec![];
+    let mut counts = vec![0; colours.len()];
+    candidate_bags(&needs, k, 0, &mut counts, &mut candidates);
+
+    candidates.sort();
+    candidates.dedup();
+    let pareto: Vec<&Vec<i64>> = candidates
+        .iter()
+        .filter(|bag| {
+            !candidates
+                .iter()
+                .any(|other| other != *bag && other.iter().zip(bag.iter()).all(|(o, b)| o <= b))
+        })
+        .collect();
+    pareto
+        .into_iter()
+        .map(|counts| Bag::from_counts(&colours, counts))
+        .collect()
+}
+
+// Fills in counts from colour onwards, adding every bag that makes k games possible.
+fn candidate_bags(
+    needs: &[Vec<i64>],
+    k: usize,
+    colour: usize,
+    counts: &mut Vec<i64>,
+    candidates: &mut Vec<Vec<i64>>,
+) {
+    let fits =
+        |need: &&Vec<i64>, counts: &[i64]| need[..colour].iter().zip(counts).all(|(n, c)| n <= c);
+    let allowed: Vec<&Vec<i64>> = needs
+        .iter()
+        .filter(|n| fits(n, &counts[..colour]))
+        .collect();
+    if allowed.len() < k {
+        return;
+    }
+    let mut values: Vec<i64> = allowed.iter().map(|need| need[colour]).collect();
+    values.sort_unstable();
+
+    if colour == counts.len() - 1 {
+        counts[colour] = values[k - 1];
+        candidates.push(counts.clone());
+        return;
+    }
+    values.dedup();
+    for value in values {
+        counts[colour] = value;
+        candidate_bags(needs, k, colour + 1, counts, candidates);
+    }
+}
+
+// Reads the games a line at a time, checking that no id turns up twice.
+struct Games<R> {
+    lines: stream::Records<R>,
+    ids: HashSet<i64>,
+    line: usize,
+}
+
+impl<R: BufRead> Games<R> {
+    fn new(input: R) -> Self {
+        Self {
+            lines: stream::lines(input),
+            ids: HashSet::new(),
+            line: 0,
+        }
+    }
+
+    fn next_game(&mut self) -> io::Result<Option<Game>> {
+        let Some(text) = self.lines.next_str()? else {
+            return Ok(None);
+        };
+        self.line += 1;
+        let game = Game::parse(text).and_then(|game| match self.ids.insert(game.id) {
+            true => Ok(game),
+            false => Err(format!("game {} is in the input twice", game.id)),
+        });
+        game.map(Some).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("line {}: {}", self.line, e),
+            )
+        })
+    }
+}
+
+// "3 blue, 4 red". Commas and newlines both separate the cubes.
+fn parse_cubes(s: &str) -> Result<Pull, String> {
+    let mut cubes = Pull::new();
+    for part in s.split([',', '\n']) {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+        let (count, colour) = match part.split_once(' ') {
+            Some((count, colour)) => (count, colour.trim()),
+            None if part.chars().all(|c| c.is_ascii_alphabetic()) => {
+                return Err(format!("missing count for '{}'", part))
+            }
+            None => return Err(format!("missing colour after '{}'", part)),
+        };
+        let count = parse_count(count).ok_or_else(|| format!("bad count '{}'", count))?;
+        if colour.is_empty() || !colour.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(format!("bad colour '{}'", colour));
+        }
+        if cubes.insert(colour.to_string(), count).is_some() {
+            return Err(format!("'{}' twice in '{}'", colour, s.trim()));
+        }
+    }
+    Ok(cubes)
+}
+
+// Just digits, so no signs.
+fn parse_count(s: &str) -> Option<i64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+// How many cubes of each colour the bag holds. The puzzle's is "12 red, 13 green, 14 blue",
+// others come from the day's bag setting, e.g.
+//
+//   aoc run 2 --set bag="12 red, 13 green, 14 blue, 4 yellow"
+//   aoc run 2 --set bag=@bag.txt
+//
+// where a bag file has the same form, with newlines allowed for the commas.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Bag {
+    limits: Pull,
+}
+
+impl Default for Bag {
+    fn default() -> Self {
+        Self::parse("12 red, 13 green, 14 blue").unwrap()
+    }
+}
+
+impl Bag {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let limits = parse_cubes(spec)?;
+        if limits.is_empty() {
+            return Err("the bag is empty".to_string());
+        }
+        Ok(Self { limits })
+    }
+
+    // The bag in the day's bag setting, or the puzzle's.
+    pub fn configured() -> io::Result<Self> {
+        let invalid = |e| io::Error::new(io::ErrorKind::InvalidInput, e);
+        match runner::setting(2023, 2, "bag").map_err(invalid)? {
+            Some(spec) => Self::parse(&spec).map_err(invalid),
+            None => Ok(Self::default()),
+        }
+    }
+
+    fn from_counts(colours: &[String], counts: &[i64]) -> Self {
+        Self {
+            limits: colours
+                .iter()
+                .cloned()
+                .zip(counts.iter().copied())
+                .collect(),
+        }
+    }
+
+    pub fn colours(&self) -> impl Iterator<Item = &str> {
+        self.limits.keys().map(String::as_str)
+    }
+
+    pub fn count(&self, colour: &str) -> i64 {
+        self.limits.get(colour).copied().unwrap_or(0)
+    }
+
+    // Whether the bag holds enough cubes for a pull.
+    pub fn is_possible(&self, pull: &Pull) -> bool {
+        pull.iter()
+            .all(|(colour, count)| *count <= self.count(colour))
+    }
+
+    // Whether the bag has at least as many of every colour as other.
+    pub fn holds(&self, other: &Bag) -> bool {
+        self.is_possible(&other.limits)
+    }
+}
+
+// "14 blue, 13 green, 12 red", the form parse reads.
+impl fmt::Display for Bag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, (colour, count)) in self.limits.iter().enumerate() {
+            write!(f, "{}{} {}", if i > 0 { ", " } else { "" }, count, colour)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_colours() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        assert_eq!(part1(input), 8.into());
+        assert_eq!(part2(input), 2286.into());
+
+        let bag = Bag::parse("12 red\n13 green, 14 blue\n2 yellow").unwrap();
+        let game = Game::parse("Game 1: 3 blue, 2 yellow; 1 red").unwrap();
+        assert!(game.is_possible(&bag));
+        let game = Game::parse("Game 2: 3 yellow").unwrap();
+        assert!(!game.is_possible(&bag));
+
+        // The games are read without knowing their colours, the bag only comes into the
+        // queries. A colour the bag doesn't have is none of it.
+        let input = "Game 1: 1 red, 2 blue\nGame 2: 1 red\nGame 3: 3 red; 2 purple";
+        let games = read_games(&mut input.as_bytes()).unwrap();
+        assert_eq!(
+            minimal_bag(&games),
+            Bag::parse("2 blue, 2 purple, 3 red").unwrap()
+        );
+        let bag = Bag::parse("1 red").unwrap();
+        let possible: Vec<bool> = games.iter().map(|g| g.is_possible(&bag)).collect();
+        assert_eq!(possible, [false, true, false]);
+        let power: Vec<i64> = games.iter().map(|g| g.power(&bag)).collect();
+        assert_eq!(power, [2, 1, 6]);
+        let bag = Bag::parse("3 red, 0 green").unwrap();
+        let power: Vec<i64> = games.iter().map(|g| g.power(&bag)).collect();
+        assert_eq!(power, [0, 0, 0]);
+        assert_eq!(possible_games(&games, &[bag]), [vec![2]]);
+
+        assert!(Bag::parse("red").is_err());
+        assert!(Bag::parse("").is_err());
+    }
+
+    #[test]
+    fn test_parse_games() {
+        assert_eq!(
+            Game::parse("Game 12: 3 blue, 4 red; 1 green"),
+            Ok(Game {
+                id: 12,
+                pulls: vec![
+                    Pull::from([("blue".to_string(), 3), ("red".to_string(), 4)]),
+                    Pull::from([("green".to_string(), 1)]),
+                ],
+            })
+        );
+        for (line, error) in [
+            ("Game x: 1 red", "bad game id 'x'"),
+            ("Game -3: 1 red", "bad game id '-3'"),
+            ("Round 1: 1 red", "expected 'Game <id>', got 'Round 1'"),
+            ("Game 1 1 red", "expected 'Game <id>: <pulls>'"),
+            ("Game 1: 1 red, blue", "missing count for 'blue'"),
+            ("Game 1: 1 red; 2", "missing colour after '2'"),
+            ("Game 1: 1 red;; 2 blue", "empty pull"),
+            ("Game 1: 1 red, 2 red", "'red' twice in '1 red, 2 red'"),
+        ] {
+            assert_eq!(Game::parse(line), Err(error.to_string()), "{}", line);
+        }
+
+        // The ids are what count, whatever order the games are in.
+        let input = "Game 5: 1 red, 1 green, 1 blue\nGame 2: 20 red, 1 green, 1 blue";
+        assert_eq!(part1(input), 5.into());
+        let mut input = "Game 5: 1 red\nGame 5: 2 red".as_bytes();
+        let error = part1_streaming(&mut input).unwrap_err();
+        assert_eq!(error.to_string(), "line 2: game 5 is in the input twice");
+    }
+
+    #[test]
+    fn test_bag_queries() {
+        let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
+        let games = read_games(&mut input.as_bytes()).unwrap();
+
+        let bag = |spec| Bag::parse(spec).unwrap();
+        assert_eq!(games[0].minimal_bag(), bag("6 blue, 2 green, 4 red"));
+        assert_eq!(minimal_bag(&games[..2]), bag("6 blue, 3 green, 4 red"));
+        assert_eq!(
+            possible_games(&games, &[Bag::default(), bag("6 blue, 3 green, 6 red")]),
+            [vec![1, 2, 5], vec![1, 2, 5]]
+        );
+
+        let pareto = |k| -> Vec<String> {
+            let bags = pareto_minimal_bags(&games, k);
+            bags.iter().map(|b| b.to_string()).collect()
+        };
+        assert_eq!(
+            pareto(1),
+            [
+                "2 blue, 3 green, 6 red",
+                "4 blue, 3 green, 1 red",
+                "6 blue, 2 green, 4 red"
+            ]
+        );
+        assert_eq!(pareto(5), ["15 blue, 13 green, 20 red"]);
+        assert_eq!(pareto(0), ["0 blue, 0 green, 0 red"]);
+        assert!(pareto(6).is_empty());
+
+        // The same as joining every k games and keeping the bags nothing else beats.
+        for k in 1..=games.len() {
+            let mut joins: Vec<Bag> = (0..1u32 << games.len())
+                .filter(|set| set.count_ones() as usize == k)
+                .map(|set| {
+                    minimal_bag(
+                        (0..games.len())
+                            .filter(|i| set & 1 << i != 0)
+                            .map(|i| &games[i]),
+                    )
+                })
+                .collect();
+            joins.dedup();
+            let mut expected: Vec<String> = joins
+                .iter()
+                .filter(|b| !joins.iter().any(|o| o != *b && b.holds(o)))
+                .map(|b| b.to_string())
+                .collect();
+            expected.sort();
+            expected.dedup();
+            let mut actual = pareto(k);
+            actual.sort();
+            assert_eq!(actual, expected, "k = {}", k);
+        }
+    }
+}
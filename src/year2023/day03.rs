@@ -0,0 +1,878 @@
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt::{self, Write},
+    io::{self, BufRead},
+};
+
+use crate::{
+    arith, debug, json, runner,
+    runner::Answer,
+    stream,
+    trace::{self, Level},
+};
+
+pub fn part1(input: &str) -> Answer {
+    stream::solve_str(part1_streaming, input)
+}
+
+pub fn part2(input: &str) -> Answer {
+    stream::solve_str(part2_streaming, input)
+}
+
+// A number can touch a symbol on the rows either side of it, so the whole schematic is kept.
+pub fn part1_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    Ok(read_graph(input)?.part_sum().into())
+}
+
+// The whole graph is written to stderr as JSON under its own target, so nothing else is mixed
+// in with it:
+//
+//   aoc run 3 --part 2 --no-cache --trace day03-graph 2> graph.json
+pub fn part2_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let rule = Rule::configured()?;
+    let graph = read_graph(input)?;
+    trace::render("day03-graph", Level::Trace, |out| {
+        writeln!(out, "{}", graph.to_json())
+    });
+    Ok(graph.score(&rule).into())
+}
+
+fn read_graph(input: &mut dyn BufRead) -> io::Result<Graph> {
+    let mut text = String::new();
+    input.read_to_string(&mut text)?;
+    Graph::parse(runner::trim_input(&text))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
+fn parse_schematic(input: &str) -> Vec<Vec<Input>> {
+    input
+        .split('\n')
+        .map(|line| line.chars().map(Input::from).collect())
+        .collect()
+}
+
+// A number in the schematic and where it sits on its row, col_end being one past its last digit.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartNumber {
+    pub value: i64,
+    pub row: usize,
+    pub col_start: usize,
+    pub col_end: usize,
+}
+
+impl PartNumber {
+    // The cells around the number. Cells past the right and bottom edges are left in, they just
+    // aren't in the schematic.
+    fn neighbours(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let cols = self.col_start.saturating_sub(1)..=self.col_end;
+        (self.row.saturating_sub(1)..=self.row + 1)
+            .flat_map(move |i| cols.clone().map(move |j| (i, j)))
+            .filter(|&(i, j)| i != self.row || j < self.col_start || j >= self.col_end)
+    }
+}
+
+// Every number in the schematic, row by row and left to right.
+fn part_numbers(schematic: &[Vec<Input>]) -> Result<Vec<PartNumber>, String> {
+    let mut numbers = vec![];
+    for (i, row) in schematic.iter().enumerate() {
+        let mut j = 0;
+        while j < row.len() {
+            match read_number(row, i, j)? {
+                Some(number) => {
+                    j = number.col_end;
+                    numbers.push(number);
+                }
+                None => j += 1,
+            }
+        }
+    }
+    Ok(numbers)
+}
+
+// The number starting at column j of row i, if there is a digit there. An error if it is too
+// big for an i64.
+fn read_number(row: &[Input], i: usize, j: usize) -> Result<Option<PartNumber>, String> {
+    let Some(Input::Number(digit)) = row.get(j) else {
+        return Ok(None);
+    };
+    let mut number = PartNumber {
+        value: *digit,
+        row: i,
+        col_start: j,
+        col_end: j + 1,
+    };
+    while let Some(Input::Number(digit)) = row.get(number.col_end) {
+        number.value = number
+            .value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(*digit))
+            .ok_or_else(|| format!("line {} col {}: number too large", i + 1, j + 1))?;
+        number.col_end += 1;
+    }
+    Ok(Some(number))
+}
+
+// A symbol in the schematic and where it is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: char,
+    pub row: usize,
+    pub col: usize,
+}
+
+// Which symbols every number is next to and the other way around, each side referring to the
+// other by its index.
+#[derive(Debug)]
+pub struct Graph {
+    pub numbers: Vec<PartNumber>,
+    pub symbols: Vec<Symbol>,
+    pub number_symbols: Vec<Vec<usize>>,
+    pub symbol_numbers: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    pub fn parse(input: &str) -> Result<Self, String> {
+        Self::new(&parse_schematic(input))
+    }
+
+    fn new(schematic: &[Vec<Input>]) -> Result<Self, String> {
+        let mut symbols = vec![];
+        let mut symbol_at = HashMap::new();
+        for (i, row) in schematic.iter().enumerate() {
+            for (j, cell) in row.iter().enumerate() {
+                if let Some(symbol) = cell.symbol() {
+                    symbol_at.insert((i, j), symbols.len());
+                    symbols.push(Symbol {
+                        symbol,
+                        row: i,
+                        col: j,
+                    });
+                }
+            }
+        }
+
+        let numbers = part_numbers(schematic)?;
+        let mut number_symbols = vec![vec![]; numbers.len()];
+        let mut symbol_numbers = vec![vec![]; symbols.len()];
+        for (n, number) in numbers.iter().enumerate() {
+            for &s in number.neighbours().filter_map(|at| symbol_at.get(&at)) {
+                number_symbols[n].push(s);
+                symbol_numbers[s].push(n);
+            }
+        }
+
+        Ok(Self {
+            numbers,
+            symbols,
+            number_symbols,
+            symbol_numbers,
+        })
+    }
+
+    // The sum of the numbers next to a symbol, the part 1 answer.
+    pub fn part_sum(&self) -> i64 {
+        let parts = self
+            .numbers
+            .iter()
+            .zip(&self.number_symbols)
+            .filter(|(_, symbols)| !symbols.is_empty())
+            .map(|(number, _)| number.value);
+        arith::sum("day03 part sum", parts)
+    }
+
+    pub fn score(&self, rule: &Rule) -> i64 {
+        match *rule {
+            Rule::Gear {
+                symbol,
+                numbers,
+                score,
+            } => {
+                let gears = self
+                    .symbols
+                    .iter()
+                    .zip(&self.symbol_numbers)
+                    .filter(|(s, adj)| symbol.is_none_or(|c| c == s.symbol) && adj.len() == numbers)
+                    .map(|(_, adj)| {
+                        let adj_nums: Vec<i64> =
+                            adj.iter().map(|&n| self.numbers[n].value).collect();
+                        debug!("day03", "adj nums: {:?}", adj_nums);
+                        score.apply(adj_nums)
+                    });
+                arith::sum("day03 gear sum", gears)
+            }
+            Rule::Kinds { kinds } => {
+                let parts = self
+                    .numbers
+                    .iter()
+                    .zip(&self.number_symbols)
+                    .filter(|(_, adj)| {
+                        let adj_kinds: BTreeSet<char> =
+                            adj.iter().map(|&s| self.symbols[s].symbol).collect();
+                        adj_kinds.len() >= kinds
+                    })
+                    .map(|(number, _)| number.value);
+                arith::sum("day03 gear sum", parts)
+            }
+        }
+    }
+
+    // Numbers and symbols each list the indices of their neighbours on the other side:
+    //
+    //   {"numbers":[{"value":467,"row":0,"col_start":0,"col_end":3,"symbols":[0]}],
+    //    "symbols":[{"symbol":"*","row":1,"col":3,"numbers":[0]}]}
+    pub fn to_json(&self) -> String {
+        let indices = |adj: &[usize]| {
+            adj.iter()
+                .map(|i| i.to_string())
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+        let numbers: Vec<String> = self
+            .numbers
+            .iter()
+            .zip(&self.number_symbols)
+            .map(|(number, adj)| {
+                format!(
+                    "{{\"value\":{},\"row\":{},\"col_start\":{},\"col_end\":{},\"symbols\":[{}]}}",
+                    number.value,
+                    number.row,
+                    number.col_start,
+                    number.col_end,
+                    indices(adj)
+                )
+            })
+            .collect();
+        let symbols: Vec<String> = self
+            .symbols
+            .iter()
+            .zip(&self.symbol_numbers)
+            .map(|(symbol, adj)| {
+                format!(
+                    "{{\"symbol\":{},\"row\":{},\"col\":{},\"numbers\":[{}]}}",
+                    json::quote(&symbol.symbol.to_string()),
+                    symbol.row,
+                    symbol.col,
+                    indices(adj)
+                )
+            })
+            .collect();
+        format!(
+            "{{\"numbers\":[{}],\"symbols\":[{}]}}",
+            numbers.join(","),
+            symbols.join(",")
+        )
+    }
+}
+
+// What part 2 scores, read from the day's gear setting:
+//
+//   "* 2 product"    the puzzle's, every '*' next to exactly two numbers scores their product
+//   "# 3 sum"        every '#' next to exactly three numbers scores their sum
+//   "any 2 product"  any symbol counts as a gear
+//   "kinds 2"        every number next to at least two different symbols scores itself
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rule {
+    Gear {
+        symbol: Option<char>,
+        numbers: usize,
+        score: Score,
+    },
+    Kinds {
+        kinds: usize,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Score {
+    Product,
+    Sum,
+}
+
+impl Score {
+    // What a gear next to these numbers scores.
+    fn apply(self, numbers: impl IntoIterator<Item = i64>) -> i64 {
+        match self {
+            Score::Product => arith::product("day03 gear ratio", numbers),
+            Score::Sum => arith::sum("day03 gear ratio", numbers),
+        }
+    }
+}
+
+impl Rule {
+    pub fn parse(spec: &str) -> Result<Self, String> {
+        let count = |n: &str| {
+            n.parse::<usize>()
+                .map_err(|_| format!("'{}' is not a count", n))
+        };
+        match spec.split_whitespace().collect::<Vec<_>>()[..] {
+            ["kinds", kinds] => Ok(Rule::Kinds {
+                kinds: count(kinds)?,
+            }),
+            [symbol, numbers, score] => {
+                let symbol = match symbol {
+                    "any" => None,
+                    _ => {
+                        let mut chars = symbol.chars();
+                        match (chars.next(), chars.next()) {
+                            (Some(c), None) if Input::from(c).symbol().is_some() => Some(c),
+                            _ => return Err(format!("'{}' is not a symbol", symbol)),
+                        }
+                    }
+                };
+                let score = match score {
+                    "product" => Score::Product,
+                    "sum" => Score::Sum,
+                    _ => return Err(format!("'{}' is not product or sum", score)),
+                };
+                Ok(Rule::Gear {
+                    symbol,
+                    numbers: count(numbers)?,
+                    score,
+                })
+            }
+            _ => Err(format!(
+                "'{}' is not '<symbol> <count> product|sum' or 'kinds <count>'",
+                spec
+            )),
+        }
+    }
+
+    // The rule in the day's gear setting, or the puzzle's.
+    pub fn configured() -> io::Result<Self> {
+        let invalid = |e| io::Error::new(io::ErrorKind::InvalidInput, e);
+        match runner::setting(2023, 3, "gear").map_err(invalid)? {
+            Some(spec) => Self::parse(&spec).map_err(|e| invalid(format!("gear setting: {}", e))),
+            None => Ok(Self::default()),
+        }
+    }
+}
+
+impl Default for Rule {
+    fn default() -> Self {
+        Rule::Gear {
+            symbol: Some('*'),
+            numbers: 2,
+            score: Score::Product,
+        }
+    }
+}
+
+// A schematic that keeps both answers up to date as its cells are edited, part 2 scored by a
+// gear rule. An edit can only change the numbers within a cell of it, by joining, splitting or
+// changing them or by moving a symbol next to them, and the gears next to those numbers. So set
+// takes those out of the sums as they were and adds them back as they are, however big the
+// schematic is.
+pub struct EditableSchematic {
+    grid: Vec<Vec<Input>>,
+    rule: Rule,
+    part_sum: i64,
+    gear_sum: i64,
+}
+
+impl EditableSchematic {
+    pub fn new(input: &str, rule: Rule) -> Result<Self, String> {
+        let grid = parse_schematic(input);
+        let graph = Graph::new(&grid)?;
+        Ok(Self {
+            part_sum: graph.part_sum(),
+            gear_sum: graph.score(&rule),
+            grid,
+            rule,
+        })
+    }
+
+    // The part 1 answer.
+    pub fn part_sum(&self) -> i64 {
+        self.part_sum
+    }
+
+    // The part 2 answer.
+    pub fn gear_sum(&self) -> i64 {
+        self.gear_sum
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, c: char) -> Result<(), String> {
+        if self.grid.get(row).is_none_or(|r| col >= r.len()) {
+            return Err(format!("row {} col {} is not in the schematic", row, col));
+        }
+        if c == '\n' {
+            return Err("a cell can't be a newline".to_string());
+        }
+
+        // Nothing changes unless every number around the cell can still be read, so an edit
+        // that joins two numbers into one too big for an i64 is refused.
+        let new = Input::from(c);
+        let old = std::mem::replace(&mut self.grid[row][col], new);
+        let after = self.numbers_around(row, col);
+        let new = std::mem::replace(&mut self.grid[row][col], old);
+        let after = after?;
+        let before = self.numbers_around(row, col)?;
+
+        // The gears that could have gained or lost a number, and the edited cell, which may
+        // have become or stopped being one.
+        let gears: BTreeSet<(usize, usize)> = before
+            .iter()
+            .chain(&after)
+            .flat_map(|number| number.neighbours())
+            .chain([(row, col)])
+            .collect();
+
+        let (part_before, gear_before) = self.sums_around(&before, &gears)?;
+        let old = std::mem::replace(&mut self.grid[row][col], new);
+        let (part_after, gear_after) = match self.sums_around(&after, &gears) {
+            Ok(sums) => sums,
+            Err(e) => {
+                self.grid[row][col] = old;
+                return Err(e);
+            }
+        };
+        self.part_sum = arith::add(
+            "day03 part sum",
+            arith::sub("day03 part sum", self.part_sum, part_before),
+            part_after,
+        );
+        self.gear_sum = arith::add(
+            "day03 gear sum",
+            arith::sub("day03 gear sum", self.gear_sum, gear_before),
+            gear_after,
+        );
+        debug!(
+            "day03",
+            "set row {} col {} to {:?}: part sum {}, gear sum {}",
+            row,
+            col,
+            c,
+            self.part_sum,
+            self.gear_sum
+        );
+        Ok(())
+    }
+
+    // What the numbers and gears given add to the part and gear sums. The numbers are the ones
+    // scored under the kinds rule, the gears the ones scored under the others.
+    fn sums_around(
+        &self,
+        numbers: &[PartNumber],
+        gears: &BTreeSet<(usize, usize)>,
+    ) -> Result<(i64, i64), String> {
+        let mut part_sum = 0;
+        for number in numbers {
+            if self.next_to_symbol(number) {
+                part_sum = arith::add("day03 part sum", part_sum, number.value);
+            }
+        }
+        let mut gear_sum = 0;
+        match self.rule {
+            Rule::Gear { .. } => {
+                for &(i, j) in gears {
+                    gear_sum = arith::add("day03 gear sum", gear_sum, self.gear_score(i, j)?);
+                }
+            }
+            Rule::Kinds { kinds } => {
+                for number in numbers {
+                    if self.symbol_kinds(number) >= kinds {
+                        gear_sum = arith::add("day03 gear sum", gear_sum, number.value);
+                    }
+                }
+            }
+        }
+        Ok((part_sum, gear_sum))
+    }
+
+    // The number with a digit at row i, column j.
+    fn number_at(&self, i: usize, j: usize) -> Result<Option<PartNumber>, String> {
+        let Some(row) = self.grid.get(i) else {
+            return Ok(None);
+        };
+        let Some(Input::Number(_)) = row.get(j) else {
+            return Ok(None);
+        };
+        let mut start = j;
+        while start > 0 && matches!(row[start - 1], Input::Number(_)) {
+            start -= 1;
+        }
+        read_number(row, i, start)
+    }
+
+    // The numbers with a digit in the cells around row i, column j or in that cell itself.
+    fn numbers_around(&self, i: usize, j: usize) -> Result<Vec<PartNumber>, String> {
+        let mut numbers: Vec<PartNumber> = vec![];
+        for row in i.saturating_sub(1)..=i + 1 {
+            for col in j.saturating_sub(1)..=j + 1 {
+                if let Some(number) = self.number_at(row, col)? {
+                    if numbers.last() != Some(&number) {
+                        numbers.push(number);
+                    }
+                }
+            }
+        }
+        Ok(numbers)
+    }
+
+    fn symbol(&self, i: usize, j: usize) -> Option<char> {
+        self.grid.get(i)?.get(j)?.symbol()
+    }
+
+    fn next_to_symbol(&self, number: &PartNumber) -> bool {
+        number
+            .neighbours()
+            .any(|(i, j)| self.symbol(i, j).is_some())
+    }
+
+    // How many different symbols the number is next to.
+    fn symbol_kinds(&self, number: &PartNumber) -> usize {
+        let kinds: BTreeSet<char> = number
+            .neighbours()
+            .filter_map(|(i, j)| self.symbol(i, j))
+            .collect();
+        kinds.len()
+    }
+
+    // What the cell at row i, column j adds to the gear sum under a gear rule.
+    fn gear_score(&self, i: usize, j: usize) -> Result<i64, String> {
+        let Rule::Gear {
+            symbol,
+            numbers,
+            score,
+        } = self.rule
+        else {
+            return Ok(0);
+        };
+        let Some(c) = self.symbol(i, j) else {
+            return Ok(0);
+        };
+        if symbol.is_some_and(|symbol| symbol != c) {
+            return Ok(0);
+        }
+        let around = self.numbers_around(i, j)?;
+        if around.len() != numbers {
+            return Ok(0);
+        }
+        Ok(score.apply(around.iter().map(|number| number.value)))
+    }
+}
+
+impl fmt::Display for EditableSchematic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.grid.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                let c = match *cell {
+                    Input::Number(digit) => char::from(b'0' + digit as u8),
+                    Input::Symbol(c) => c,
+                    Input::Gear => '*',
+                    Input::Nothing => '.',
+                };
+                write!(f, "{}", c)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Input {
+    Number(i64),
+    Symbol(char),
+    Gear,
+    Nothing,
+}
+
+impl From<char> for Input {
+    fn from(value: char) -> Self {
+        match value {
+            value if value.is_ascii_digit() => Input::Number(value.to_digit(10).unwrap() as i64),
+            '.' => Input::Nothing,
+            '*' => Input::Gear,
+            _ => Input::Symbol(value),
+        }
+    }
+}
+
+impl Input {
+    fn symbol(&self) -> Option<char> {
+        match *self {
+            Input::Symbol(c) => Some(c),
+            Input::Gear => Some('*'),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<Input> for i64 {
+    type Error = String;
+
+    fn try_from(value: Input) -> Result<Self, Self::Error> {
+        if let Input::Number(i) = value {
+            Ok(i)
+        } else {
+            Err("Not a number".to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::gen::Rng;
+
+    fn sum_gear_ratios(schematic: &[Vec<Input>]) -> i64 {
+        Graph::new(schematic).unwrap().score(&Rule::default())
+    }
+
+    #[test]
+    fn test_part2_inputs() {
+        // Sample
+        let inputs: Vec<(&str, i64)> = vec![
+            (
+                r#"467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598.."#,
+                467835,
+            ),
+            // Both all 3
+            (
+                r#"890
+.*.
+904"#,
+                804560,
+            ),
+            // Left and Right
+            (
+                r#"890
+.*.
+904"#,
+                804560,
+            ),
+            // Two diagonals
+            (
+                r#"890....
+...*...
+....904"#,
+                804560,
+            ),
+            (
+                r#"....890
+...*...
+904...."#,
+                804560,
+            ),
+            // Single digits
+            (
+                r#"...3....
+...*...
+...7..."#,
+                21,
+            ),
+            // Left and Right above
+            (
+                r#"571.342
+...*...
+......."#,
+                195282,
+            ),
+        ];
+
+        for input in inputs {
+            let schematic = parse_schematic(input.0);
+            let answer = sum_gear_ratios(&schematic);
+
+            assert_eq!(answer, input.1)
+        }
+    }
+
+    #[test]
+    fn test_part_numbers() {
+        let schematic = parse_schematic("467..114..\n...*......\n12345#..9");
+        assert_eq!(
+            part_numbers(&schematic).unwrap(),
+            [
+                PartNumber {
+                    value: 467,
+                    row: 0,
+                    col_start: 0,
+                    col_end: 3
+                },
+                PartNumber {
+                    value: 114,
+                    row: 0,
+                    col_start: 5,
+                    col_end: 8
+                },
+                PartNumber {
+                    value: 12345,
+                    row: 2,
+                    col_start: 0,
+                    col_end: 5
+                },
+                PartNumber {
+                    value: 9,
+                    row: 2,
+                    col_start: 8,
+                    col_end: 9
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_wide_numbers() {
+        // A number only touches a symbol through its span, however long it is.
+        assert_eq!(part1("123456..\n.......#"), 0.into());
+        assert_eq!(part1("1234567\n......#"), 1234567.into());
+        // Two numbers on the row above make a gear, unless a third touches it as well.
+        assert_eq!(sum_gear_ratios(&parse_schematic("1.2\n.*.")), 2);
+        assert_eq!(sum_gear_ratios(&parse_schematic("1.2\n.*3")), 0);
+        assert_eq!(
+            sum_gear_ratios(&parse_schematic("10000*20000\n...........")),
+            200000000
+        );
+    }
+
+    #[test]
+    fn test_gear_rules() {
+        let sample = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+        let graph = Graph::parse(sample).unwrap();
+        let score = |spec| graph.score(&Rule::parse(spec).unwrap());
+        assert_eq!(score("* 2 product"), 467835);
+        assert_eq!(score("* 2 sum"), 467 + 35 + 755 + 598);
+        assert_eq!(score("# 1 sum"), 633);
+        assert_eq!(score("any 1 sum"), 633 + 617 + 592 + 664);
+        assert_eq!(score("kinds 1"), 4361);
+        assert_eq!(score("kinds 2"), 0);
+        assert_eq!(
+            Graph::parse("1*2#3")
+                .unwrap()
+                .score(&Rule::Kinds { kinds: 2 }),
+            2
+        );
+
+        assert_eq!(
+            Rule::parse("* two product"),
+            Err("'two' is not a count".to_string())
+        );
+        assert_eq!(
+            Rule::parse(". 2 sum"),
+            Err("'.' is not a symbol".to_string())
+        );
+        assert_eq!(
+            Rule::parse("** 2 sum"),
+            Err("'**' is not a symbol".to_string())
+        );
+        assert!(Rule::parse("* 2 max").is_err());
+        assert!(Rule::parse("* 2").is_err());
+    }
+
+    #[test]
+    fn test_graph_json() {
+        assert_eq!(
+            Graph::parse("1*2\n..\"").unwrap().to_json(),
+            r#"{"numbers":[{"value":1,"row":0,"col_start":0,"col_end":1,"symbols":[0]},{"value":2,"row":0,"col_start":2,"col_end":3,"symbols":[0,1]}],"symbols":[{"symbol":"*","row":0,"col":1,"numbers":[0,1]},{"symbol":"\"","row":1,"col":2,"numbers":[1]}]}"#
+        );
+    }
+
+    #[test]
+    fn test_editable_schematic() {
+        let mut schematic =
+            EditableSchematic::new("467..114..\n...*......\n..35..633.", Rule::default()).unwrap();
+        assert_eq!((schematic.part_sum(), schematic.gear_sum()), (502, 16345));
+        // Joining 114 onto 467 over the gap, then moving the gear away from the numbers.
+        schematic.set(0, 3, '0').unwrap();
+        schematic.set(0, 4, '0').unwrap();
+        assert_eq!(schematic.to_string(), "46700114..\n...*......\n..35..633.");
+        assert_eq!(schematic.part_sum(), 46700114 + 35);
+        assert_eq!(schematic.gear_sum(), 46700114 * 35);
+        schematic.set(1, 3, '.').unwrap();
+        schematic.set(1, 9, '*').unwrap();
+        assert_eq!((schematic.part_sum(), schematic.gear_sum()), (633, 0));
+
+        assert!(schematic.set(3, 0, '1').is_err());
+        assert!(schematic.set(0, 10, '1').is_err());
+
+        // Joining two numbers into one too big for an i64 leaves the schematic as it was.
+        let input = "9999999999.9999999999\n..........#..........";
+        let mut schematic = EditableSchematic::new(input, Rule::default()).unwrap();
+        assert_eq!(
+            schematic.set(0, 10, '9'),
+            Err("line 1 col 1: number too large".to_string())
+        );
+        assert_eq!(schematic.to_string(), input);
+        assert_eq!(
+            (schematic.part_sum(), schematic.gear_sum()),
+            (2 * 9999999999, 0)
+        );
+        assert_eq!(
+            Graph::parse("99999999999999999999").err(),
+            Some("line 1 col 1: number too large".to_string())
+        );
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    #[should_panic(expected = "day03 gear ratio: 9999999999 * 9999999999 overflows i64")]
+    fn test_gear_ratio_overflow() {
+        part2("9999999999*9999999999");
+    }
+
+    #[cfg(feature = "checked")]
+    #[test]
+    #[should_panic(expected = "day03 gear ratio: 9999999999 * 9999999999 overflows i64")]
+    fn test_editable_gear_ratio_overflow() {
+        let input = "9999999999.9999999999";
+        let mut schematic = EditableSchematic::new(input, Rule::default()).unwrap();
+        let _ = schematic.set(0, 10, '*');
+    }
+
+    #[test]
+    fn test_editable_schematic_matches_recomputing() {
+        // Mostly digits and gears, so edits keep joining and splitting numbers next to gears.
+        const CELLS: &[char] = &['.', '.', '.', '*', '*', '#', '1', '2', '5', '7', '9'];
+        const RULES: &[&str] = &["* 2 product", "# 1 sum", "any 0 product", "kinds 2"];
+        for seed in 0..200 {
+            let mut rng = Rng::new(seed);
+            let rule = Rule::parse(RULES[rng.index(RULES.len())]).unwrap();
+            let (rows, cols) = (rng.index(6) + 1, rng.index(8) + 1);
+            let input = (0..rows)
+                .map(|_| (0..cols).map(|_| *rng.choose(CELLS)).collect::<String>())
+                .collect::<Vec<_>>()
+                .join("\n");
+
+            let mut schematic = EditableSchematic::new(&input, rule).unwrap();
+            for _ in 0..30 {
+                let (row, col) = (rng.index(rows), rng.index(cols));
+                schematic.set(row, col, *rng.choose(CELLS)).unwrap();
+
+                let edited = schematic.to_string();
+                let recomputed = EditableSchematic::new(&edited, rule).unwrap();
+                assert_eq!(
+                    (schematic.part_sum(), schematic.gear_sum()),
+                    (recomputed.part_sum(), recomputed.gear_sum()),
+                    "seed {} with {:?} after setting row {} col {}:\n{}",
+                    seed,
+                    rule,
+                    row,
+                    col,
+                    edited
+                );
+            }
+        }
+    }
+}
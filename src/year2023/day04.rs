@@ -0,0 +1,313 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    io::{self, BufRead, Write},
+};
+
+use crate::{
+    runner::Answer,
+    stream,
+    trace::{self, Level},
+};
+
+pub fn part1(input: &str) -> Answer {
+    stream::solve_str(part1_streaming, input)
+}
+
+pub fn part2(input: &str) -> Answer {
+    stream::solve_str(part2_streaming, input)
+}
+
+pub fn part1_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let mut lines = stream::lines(input);
+    let mut part_1: u128 = 0;
+    while let Some(line) = lines.next_str()? {
+        let card = Card::parse(line).map_err(invalid)?;
+        part_1 = part_1
+            .checked_add(card.score().map_err(invalid)?)
+            .ok_or_else(|| invalid(format!("card {}: the total overflows u128", card.id)))?;
+    }
+    Ok(part_1.into())
+}
+
+// Every card's breakdown is written to stderr as CSV under its own target, so nothing else is
+// mixed in with it. Only then are the breakdowns all kept until the end:
+//
+//   aoc run 4 --part 2 --no-cache --trace day04-csv 2> cards.csv
+pub fn part2_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    const CSV: &str = "day04-csv";
+    let mut part2: u128 = 0;
+    if trace::enabled(CSV, Level::Trace) {
+        let cards = breakdown(input)?;
+        let mut csv = vec![];
+        write_csv(&mut csv, &cards)?;
+        trace::render(CSV, Level::Trace, |out| {
+            out.push_str(&String::from_utf8_lossy(&csv));
+            Ok(())
+        });
+        for card in &cards {
+            part2 = add_total(part2, card)?;
+        }
+        return Ok(part2.into());
+    }
+
+    let mut lines = stream::lines(input);
+    let mut cascade = Cascade::new(false);
+    while let Some(line) = lines.next_str()? {
+        let card = Card::parse(line).map_err(invalid)?;
+        part2 = add_total(part2, &cascade.play(&card).map_err(invalid)?)?;
+    }
+    Ok(part2.into())
+}
+
+fn add_total(total: u128, card: &CardBreakdown) -> io::Result<u128> {
+    total
+        .checked_add(card.total())
+        .ok_or_else(|| invalid(format!("card {}: the total overflows u128", card.id)))
+}
+
+// How many of every card there are by the end, and where they came from.
+pub fn breakdown(input: &mut dyn BufRead) -> io::Result<Vec<CardBreakdown>> {
+    let mut lines = stream::lines(input);
+    let mut cascade = Cascade::new(true);
+    let mut cards = vec![];
+    while let Some(line) = lines.next_str()? {
+        let card = Card::parse(line).map_err(invalid)?;
+        cards.push(cascade.play(&card).map_err(invalid)?);
+    }
+    Ok(cards)
+}
+
+pub fn write_csv(out: &mut impl Write, cards: &[CardBreakdown]) -> io::Result<()> {
+    writeln!(out, "{}", CardBreakdown::CSV_HEADER)?;
+    for card in cards {
+        writeln!(out, "{}", card.csv_row())?;
+    }
+    Ok(())
+}
+
+fn invalid(e: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e)
+}
+
+struct Card {
+    id: u32,
+    // How many of the numbers we have are winning numbers.
+    wins: usize,
+}
+
+impl Card {
+    fn parse(line: &str) -> Result<Self, String> {
+        let (id, numbers) = line
+            .split_once(':')
+            .ok_or_else(|| format!("'{}' is not a card", line))?;
+        let id = id
+            .strip_prefix("Card")
+            .and_then(|id| id.trim().parse().ok())
+            .ok_or_else(|| format!("'{}' is not a card id", id))?;
+        let (winning, have) = numbers
+            .split_once('|')
+            .ok_or_else(|| format!("card {}: no '|' between the numbers", id))?;
+
+        let number = |n: &str| {
+            n.parse::<i64>()
+                .map_err(|_| format!("card {}: '{}' is not a number", id, n))
+        };
+        let winning = winning
+            .split_ascii_whitespace()
+            .map(number)
+            .collect::<Result<HashSet<i64>, String>>()?;
+        let mut wins = 0;
+        for n in have.split_ascii_whitespace() {
+            if winning.contains(&number(n)?) {
+                wins += 1;
+            }
+        }
+        Ok(Self { id, wins })
+    }
+
+    // Doubles with every win after the first, so like the copies it is counted as u128, and
+    // past that it is an error.
+    fn score(&self) -> Result<u128, String> {
+        match self.wins {
+            0 => Ok(0),
+            wins => u32::try_from(wins - 1)
+                .ok()
+                .and_then(|doublings| 2_u128.checked_pow(doublings))
+                .ok_or_else(|| format!("card {}: {} wins score more than a u128", self.id, wins)),
+        }
+    }
+}
+
+// One card's count at the end of the cascade: the original, the copies won, and the earlier cards
+// that won them, each with the number of copies it handed over.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CardBreakdown {
+    pub id: u32,
+    pub original: u128,
+    pub copies: u128,
+    pub from: Vec<(u32, u128)>,
+}
+
+impl CardBreakdown {
+    pub const CSV_HEADER: &'static str = "card,original,copies,total,from";
+
+    pub fn total(&self) -> u128 {
+        // Never overflows, the cascade checks it before handing out this card's copies.
+        self.original + self.copies
+    }
+
+    // The from column lists the cards as "id:copies" separated by spaces.
+    pub fn csv_row(&self) -> String {
+        let from: Vec<String> = self
+            .from
+            .iter()
+            .map(|(id, copies)| format!("{}:{}", id, copies))
+            .collect();
+        format!(
+            "{},{},{},{},{}",
+            self.id,
+            self.original,
+            self.copies,
+            self.total(),
+            from.join(" ")
+        )
+    }
+}
+
+// Plays the cards in order, each card handing one copy of the next few cards to every one of its
+// instances. Only the copies won of the cards still to come are kept, and a card only wins copies
+// of the next few cards, so this stays short however many cards there are. Copies of cards past
+// the end of the table are never counted.
+struct Cascade {
+    copies_won: VecDeque<u128>,
+    // Which cards won those copies, when the breakdown is wanted.
+    won_from: Option<VecDeque<Vec<(u32, u128)>>>,
+}
+
+impl Cascade {
+    fn new(track_from: bool) -> Self {
+        Self {
+            copies_won: VecDeque::new(),
+            won_from: track_from.then(VecDeque::new),
+        }
+    }
+
+    // Counts copies as u128, which the copies of a long enough run of cards overflow all the
+    // same, so that is an error.
+    fn play(&mut self, card: &Card) -> Result<CardBreakdown, String> {
+        let copies = self.copies_won.pop_front().unwrap_or(0);
+        let from = self
+            .won_from
+            .as_mut()
+            .and_then(|won_from| won_from.pop_front())
+            .unwrap_or_default();
+        let overflow = || format!("card {}: the copies won overflow u128", card.id);
+        let total = copies.checked_add(1).ok_or_else(overflow)?;
+
+        if self.copies_won.len() < card.wins {
+            self.copies_won.resize(card.wins, 0);
+        }
+        for won in self.copies_won.iter_mut().take(card.wins) {
+            *won = won.checked_add(total).ok_or_else(overflow)?;
+        }
+        if let Some(won_from) = &mut self.won_from {
+            if won_from.len() < card.wins {
+                won_from.resize(card.wins, vec![]);
+            }
+            for from in won_from.iter_mut().take(card.wins) {
+                from.push((card.id, total));
+            }
+        }
+
+        Ok(CardBreakdown {
+            id: card.id,
+            original: 1,
+            copies,
+            from,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const EXAMPLE: &str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+    #[test]
+    fn test_breakdown() {
+        assert_eq!(part1(EXAMPLE), 13.into());
+        assert_eq!(part2(EXAMPLE), 30.into());
+
+        let counts = breakdown(&mut EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(
+            counts[3],
+            CardBreakdown {
+                id: 4,
+                original: 1,
+                copies: 7,
+                from: vec![(1, 1), (2, 2), (3, 4)],
+            }
+        );
+
+        let mut csv = vec![];
+        write_csv(&mut csv, &counts).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "card,original,copies,total,from
+1,1,0,1,
+2,1,1,2,1:1
+3,1,3,4,1:1 2:2
+4,1,7,8,1:1 2:2 3:4
+5,1,13,14,1:1 3:4 4:8
+6,1,0,1,
+"
+        );
+    }
+
+    #[test]
+    fn test_big_scores() {
+        let card = |wins: usize| {
+            let numbers: Vec<String> = (1..=wins).map(|n| n.to_string()).collect();
+            format!("Card 1: {} | {}", numbers.join(" "), numbers.join(" "))
+        };
+        assert_eq!(part1(&card(63)), (1_u128 << 62).into());
+        assert_eq!(part1(&card(64)), (1_u128 << 63).into());
+        assert_eq!(part1(&card(65)), (1_u128 << 64).into());
+
+        let err = part1_streaming(&mut card(129).as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), "card 1: 129 wins score more than a u128");
+        let two = format!("{}\n{}", card(128), card(128).replace("Card 1", "Card 2"));
+        let err = part1_streaming(&mut two.as_bytes()).unwrap_err();
+        assert_eq!(err.to_string(), "card 2: the total overflows u128");
+    }
+
+    #[test]
+    fn test_big_counts() {
+        // Every card winning a copy of each of the next two makes the counts go up like the
+        // Fibonacci numbers, past an i64 long before a u128.
+        let cards = |n: usize| {
+            (1..=n)
+                .map(|id| format!("Card {}: 1 2 | 1 2", id))
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+        let (mut before, mut last, mut sum) = (0_u128, 0_u128, 0_u128);
+        for _ in 0..100 {
+            (before, last) = (last, 1 + before + last);
+            sum += last;
+        }
+        assert!(sum > i64::MAX as u128);
+        assert_eq!(part2(&cards(100)), sum.into());
+
+        let err = part2_streaming(&mut cards(200).as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("overflow"), "{}", err);
+    }
+}
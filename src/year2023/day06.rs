@@ -0,0 +1,94 @@
+use std::io::{self, BufRead};
+
+use crate::{arith, runner::Answer, stream};
+
+pub fn part1(input: &str) -> Answer {
+    stream::solve_str(part1_streaming, input)
+}
+
+pub fn part2(input: &str) -> Answer {
+    stream::solve_str(part2_streaming, input)
+}
+
+// Only the times are kept, the distances are paired up with them as they are read.
+pub fn part1_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let mut lines = stream::lines(input);
+    let times: Vec<i64> = numbers(next_line(&mut lines)?).collect();
+    let distances = numbers(next_line(&mut lines)?);
+
+    let ways_to_beat_record = times.iter().zip(distances).map(|(time, distance)| {
+        let mut ways = 0;
+        for hold_duration in 1..*time {
+            let distance_travelled = (time - hold_duration) * hold_duration;
+            if distance_travelled > distance {
+                ways += 1;
+            }
+        }
+        ways
+    });
+
+    let part1: i64 = arith::product("day06 part1", ways_to_beat_record);
+    Ok(part1.into())
+}
+
+pub fn part2_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let mut lines = stream::lines(input);
+    let time2 = kerned_number("time", next_line(&mut lines)?)?;
+    let distance2 = kerned_number("distance", next_line(&mut lines)?)?;
+
+    let mut ways_to_beat_record: i64 = 0;
+    for hold_duration in 1..time2 {
+        let distance_travelled = arith::mul("day06 race", time2 - hold_duration, hold_duration);
+        if distance_travelled > distance2 {
+            ways_to_beat_record += 1;
+        }
+    }
+
+    Ok(ways_to_beat_record.into())
+}
+
+fn next_line<R: BufRead>(lines: &mut stream::Records<R>) -> io::Result<&str> {
+    lines
+        .next_str()?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "expected another line"))
+}
+
+// The numbers on a line after its label.
+fn numbers(line: &str) -> impl Iterator<Item = i64> + '_ {
+    line.split_ascii_whitespace()
+        .skip(1)
+        .map(|s| s.parse::<i64>().unwrap())
+}
+
+// The one number a line's numbers make with the spaces taken out, which can be too big for an
+// i64 even though each of them fits.
+fn kerned_number(what: &str, line: &str) -> io::Result<i64> {
+    let numbers: Vec<String> = numbers(line).map(|i| i.to_string()).collect();
+    let kerned = numbers.join("");
+    kerned.parse::<i64>().map_err(|e| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("day06 race {}: {}: {}", what, kerned, e),
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_kerned_numbers() {
+        let input = "Time:      7  15   30\nDistance:  9  40  200";
+        assert_eq!(part1(input), 288.into());
+        assert_eq!(part2(input), 71503.into());
+
+        let input = "Time: 9999999999 9999999999\nDistance: 1";
+        let err = part2_streaming(&mut input.as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            "day06 race time: 99999999999999999999: number too large to fit in target type"
+        );
+    }
+}
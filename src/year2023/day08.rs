@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use crate::{
+    arith, info,
+    runner::{Answer, Assumption},
+};
+
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption {
+        name: "AAA and ZZZ exist",
+        check: start_and_end_exist,
+    },
+    Assumption {
+        name: "steps to the first Z equal the cycle length",
+        check: first_z_equals_cycle_length,
+    },
+];
+
+pub fn part1(input: &str) -> Answer {
+    let (instructions, nodes) = input.split_once("\n\n").unwrap();
+    let map = Nodes::from(nodes);
+
+    let mut current = "AAA";
+    let mut steps_req = 0;
+    for (steps, i) in instructions.chars().cycle().enumerate() {
+        let current_map = map.mappings.get(current).unwrap();
+        current = match i {
+            'L' => &current_map.0,
+            'R' => &current_map.1,
+            _ => panic!("unexpected instruction"),
+        };
+        if current == "ZZZ" {
+            steps_req = steps + 1;
+            break;
+        }
+    }
+
+    steps_req.into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let (instructions, nodes) = input.split_once("\n\n").unwrap();
+    let map = Nodes::from(nodes);
+
+    let mut current_nodes: Vec<String> = map
+        .mappings
+        .keys()
+        .filter_map(|s| {
+            if s.ends_with('A') {
+                let node = s.clone();
+                Some(node)
+            } else {
+                None
+            }
+        })
+        .collect();
+    // for each starting loc - when do we start cycling landing on a "Z".
+    let mut z_seen_before: Vec<bool> = vec![false; current_nodes.len()];
+    let mut steps_for_first_z: Vec<i64> = vec![i64::MAX; current_nodes.len()];
+    let mut step_for_last_seen_z: Vec<i64> = vec![i64::MAX; current_nodes.len()];
+    let mut z_cycle: Vec<i64> = vec![i64::MAX; current_nodes.len()];
+    let mut z_cycle2: Vec<i64> = vec![i64::MAX; current_nodes.len()];
+    for (steps, i) in instructions.chars().cycle().enumerate() {
+        current_nodes = current_nodes
+            .iter()
+            .map({
+                |current| {
+                    let current_map = map.mappings.get(current).unwrap();
+                    let new = match i {
+                        'L' => &current_map.0,
+                        'R' => &current_map.1,
+                        _ => panic!("unexpected instruction"),
+                    };
+                    new.into()
+                }
+            })
+            .collect();
+
+        for (j, node) in current_nodes.iter().enumerate() {
+            if node.ends_with('Z') {
+                if !z_seen_before[j] {
+                    steps_for_first_z[j] = steps as i64 + 1;
+                    z_seen_before[j] = true;
+                } else {
+                    if z_cycle[j] == i64::MAX {
+                        z_cycle[j] = steps as i64 + 1 - steps_for_first_z[j];
+                    } else {
+                        z_cycle2[j] = steps as i64 + 1 - (step_for_last_seen_z[j]);
+                    }
+
+                    step_for_last_seen_z[j] = steps as i64 + 1;
+                }
+            }
+        }
+        if z_cycle2.iter().all(|z| z < &i64::MAX) {
+            break;
+        }
+    }
+    // Assert that each cycle is actually consistent.
+    for (i, step) in z_cycle2.iter().enumerate() {
+        debug_assert_eq!(*step, z_cycle[i]);
+    }
+    // I got lucky here, not sure if it's guaranteed that both my first steps and cycle lengths were consistent.
+    info!("day08", "cycle lengths: {:?}", z_cycle2);
+    // Six cycles of a few thousand steps each can outgrow an i64.
+    let lcm = z_cycle2.into_iter().map(i128::from).reduce(lcm).unwrap();
+    lcm.into()
+}
+
+fn start_and_end_exist(input: &str) -> Result<(), String> {
+    let (_, nodes) = input
+        .split_once("\n\n")
+        .ok_or("no blank line after the instructions")?;
+    let map = Nodes::from(nodes);
+    for node in ["AAA", "ZZZ"] {
+        if !map.mappings.contains_key(node) {
+            return Err(format!("there is no {} node", node));
+        }
+    }
+    Ok(())
+}
+
+// Part 2 takes the LCM of the cycle lengths, which is only the answer if every ghost reaches
+// its first Z after exactly one cycle length.
+fn first_z_equals_cycle_length(input: &str) -> Result<(), String> {
+    let (instructions, nodes) = input
+        .split_once("\n\n")
+        .ok_or("no blank line after the instructions")?;
+    let map = Nodes::from(nodes);
+    let instructions: Vec<char> = instructions.chars().collect();
+    // After this many steps a ghost must have repeated a (node, instruction) state.
+    let max_steps = instructions.len() * (map.mappings.len() + 1);
+
+    let mut starts: Vec<&String> = map.mappings.keys().filter(|s| s.ends_with('A')).collect();
+    starts.sort();
+    for start in starts {
+        let mut current = start;
+        let mut z_steps = vec![];
+        for step in 0..max_steps {
+            let current_map = map
+                .mappings
+                .get(current)
+                .ok_or_else(|| format!("node {} is never defined", current))?;
+            current = match instructions[step % instructions.len()] {
+                'L' => &current_map.0,
+                'R' => &current_map.1,
+                i => return Err(format!("unexpected instruction {}", i)),
+            };
+            if current.ends_with('Z') {
+                z_steps.push(step + 1);
+                if z_steps.len() == 2 {
+                    break;
+                }
+            }
+        }
+
+        match z_steps[..] {
+            [first, second] if second - first == first => {}
+            [first, second] => {
+                return Err(format!(
+                    "{} first reaches a Z after {} steps but cycles every {}",
+                    start,
+                    first,
+                    second - first
+                ))
+            }
+            _ => return Err(format!("{} doesn't keep reaching a Z", start)),
+        }
+    }
+    Ok(())
+}
+
+struct Nodes {
+    // node to L/R string.
+    mappings: HashMap<String, (String, String)>,
+}
+
+impl From<&str> for Nodes {
+    fn from(value: &str) -> Self {
+        let mut mappings = HashMap::<String, (String, String)>::new();
+        for line in value.lines() {
+            let (source, dests) = line.split_once(" = ").unwrap();
+            let (dest_l, dest_r) = dests.split_once(", ").unwrap();
+
+            mappings.insert(
+                source.to_string(),
+                (
+                    dest_l[1..].to_string(),
+                    dest_r[0..dest_r.len() - 1].to_string(),
+                ),
+            );
+        }
+        Self { mappings }
+    }
+}
+
+fn lcm(x: i128, y: i128) -> i128 {
+    arith::mul("day08 lcm", x, y) / gcd(x, y)
+}
+
+fn gcd(x: i128, y: i128) -> i128 {
+    let start = if y < x { y } else { x };
+    for i in (1..start).rev() {
+        if y % i == 0 && x % i == 0 {
+            return i;
+        }
+    }
+    panic!("not found");
+}
@@ -0,0 +1,53 @@
+use std::io::{self, BufRead};
+
+use crate::{runner::Answer, stream};
+
+pub fn part1(input: &str) -> Answer {
+    stream::solve_str(part1_streaming, input)
+}
+
+pub fn part2(input: &str) -> Answer {
+    stream::solve_str(part2_streaming, input)
+}
+
+pub fn part1_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    sum_sequences(input, extrapolate_sequence)
+}
+
+pub fn part2_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    sum_sequences(input, extrapolate_sequence_backward)
+}
+
+fn sum_sequences(input: &mut dyn BufRead, extrapolate: fn(&[i64]) -> i64) -> io::Result<Answer> {
+    let mut lines = stream::lines(input);
+    let mut sequence = vec![];
+    let mut sum: i64 = 0;
+    while let Some(line) = lines.next_str()? {
+        sequence.clear();
+        sequence.extend(line.split(' ').map(|s| s.parse::<i64>().unwrap()));
+        sum += extrapolate(&sequence);
+    }
+    Ok(sum.into())
+}
+
+fn extrapolate_sequence(seq: &[i64]) -> i64 {
+    let seq_diffs: Vec<i64> = seq.windows(2).map(|win| win[1] - win[0]).collect();
+    if seq_diffs.iter().all(|i| *i == 0) {
+        // this means all elements on seq are the same, so just return the first element.
+        seq[0]
+    } else {
+        let diff = extrapolate_sequence(&seq_diffs);
+        seq.last().unwrap() + diff
+    }
+}
+
+fn extrapolate_sequence_backward(seq: &[i64]) -> i64 {
+    let seq_diffs: Vec<i64> = seq.windows(2).map(|win| win[1] - win[0]).collect();
+    if seq_diffs.iter().all(|i| *i == 0) {
+        // this means all elements on seq are the same, so just return the first element.
+        seq[0]
+    } else {
+        let diff = extrapolate_sequence_backward(&seq_diffs);
+        seq.first().unwrap() - diff
+    }
+}
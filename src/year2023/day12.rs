@@ -0,0 +1,195 @@
+use std::io::{self, BufRead};
+
+use itertools::Itertools;
+
+use crate::{debug, memo::Memo, runner::Answer, stream};
+
+pub fn part1(input: &str) -> Answer {
+    stream::solve_str(part1_streaming, input)
+}
+
+pub fn part2(input: &str) -> Answer {
+    stream::solve_str(part2_streaming, input)
+}
+
+pub fn part1_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    sum_arrangements(input, |record| record)
+}
+
+pub fn part2_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    sum_arrangements(input, |record| {
+        let mut new_row: Vec<char> = vec![];
+        let mut new_springs = vec![];
+
+        for i in 0..5 {
+            new_row.extend(record.row.iter());
+            if i != 4 {
+                new_row.push('?');
+            }
+            new_springs.extend(record.springs.iter());
+        }
+
+        Record {
+            row: new_row,
+            springs: new_springs,
+        }
+    })
+}
+
+// The memo is cleared for every record anyway, so records are solved as they are read.
+fn sum_arrangements(input: &mut dyn BufRead, unfold: fn(Record) -> Record) -> io::Result<Answer> {
+    let mut memo = Memo::new();
+    let mut lines = stream::lines(input);
+
+    let mut sum: u64 = 0;
+    while let Some(line) = lines.next_str()? {
+        sum += unfold(Record::from_line(line)).possible_arrangements(&mut memo);
+    }
+    debug!("day12", "memo: {}", memo.stats());
+    Ok(sum.into())
+}
+
+// Every remainder is a suffix of the record's row, except that a leading '?' may have been
+// decided, and every to_resolve is a suffix of its springs, except that the first group may be
+// partly resolved. So within one record their lengths and first elements identify them:
+// (remainder len, first char, to_resolve len, first group, midway, needs_gap).
+type Key = (usize, Option<char>, usize, Option<u64>, bool, bool);
+
+#[derive(Debug)]
+struct Record {
+    row: Vec<char>,
+    springs: Vec<u64>,
+}
+
+impl Record {
+    fn from_line(line: &str) -> Self {
+        let (row_str, springs_str) = line.split_once(' ').unwrap();
+
+        Record {
+            row: row_str.chars().collect_vec(),
+            springs: springs_str.split(',').map(|s| s.parse().unwrap()).collect(),
+        }
+    }
+
+    fn possible_arrangements(&self, memo: &mut Memo<Key, u64>) -> u64 {
+        // The keys only make sense for this record.
+        memo.clear();
+        Record::possible_arrangements_inner(&self.row, &self.springs, false, false, memo)
+    }
+
+    fn possible_arrangements_inner(
+        remainder: &[char],
+        to_resolve: &[u64],
+        midway: bool,
+        needs_gap: bool,
+        memo: &mut Memo<Key, u64>,
+    ) -> u64 {
+        // The moment we hit a ?, we recurse twice to cover the two options.
+        // Eliminate impossible options.
+        let mut new_to_resolve = vec![0; to_resolve.len()];
+        new_to_resolve.clone_from_slice(to_resolve);
+
+        let key = (
+            remainder.len(),
+            remainder.first().copied(),
+            to_resolve.len(),
+            to_resolve.first().copied(),
+            midway,
+            needs_gap,
+        );
+
+        if let Some(result) = memo.get(&key) {
+            return *result;
+        };
+
+        let result = match remainder.first() {
+            Some(c) => {
+                match c {
+                    '?' => {
+                        let mut possible_arrangements = 0;
+                        let mut remainder1 = vec![' '; remainder.len()];
+                        let mut remainder2 = vec![' '; remainder.len()];
+                        remainder1.clone_from_slice(remainder);
+                        remainder2.clone_from_slice(remainder);
+
+                        remainder1[0] = '#';
+                        remainder2[0] = '.';
+                        possible_arrangements += Record::possible_arrangements_inner(
+                            &remainder1,
+                            to_resolve,
+                            midway,
+                            needs_gap,
+                            memo,
+                        );
+                        possible_arrangements += Record::possible_arrangements_inner(
+                            &remainder2,
+                            to_resolve,
+                            midway,
+                            needs_gap,
+                            memo,
+                        );
+
+                        possible_arrangements
+                    }
+                    '.' => {
+                        // If we encounter a dot and we are midway in resolving a group, then this is an impossiblitiy.
+                        if midway {
+                            0
+                        } else {
+                            let new_remainder: &[char] = &remainder[1..];
+                            Record::possible_arrangements_inner(
+                                new_remainder,
+                                &new_to_resolve,
+                                false,
+                                false,
+                                memo,
+                            )
+                        }
+                    }
+                    '#' => {
+                        if needs_gap || new_to_resolve.is_empty() {
+                            0
+                        } else {
+                            let new_remainder: &[char] = &remainder[1..];
+                            let new_midway: bool;
+                            let new_needs_gap: bool;
+
+                            // We've gotten to the end of a block.
+                            if (new_to_resolve[0] - 1) == 0 {
+                                new_to_resolve = new_to_resolve[1..].to_vec();
+                                new_midway = false;
+                                new_needs_gap = true;
+                            } else {
+                                new_to_resolve[0] -= 1;
+                                new_midway = true;
+                                new_needs_gap = false;
+                            };
+
+                            Record::possible_arrangements_inner(
+                                new_remainder,
+                                &new_to_resolve,
+                                new_midway,
+                                new_needs_gap,
+                                memo,
+                            )
+                        }
+                    }
+                    _ => {
+                        panic!("unexpected char")
+                    }
+                }
+            }
+            None => {
+                // There are no chars left, but we expect some more springs.
+                if to_resolve.is_empty() {
+                    1
+                } else {
+                    0
+                }
+            }
+        };
+
+        memo.insert(key, result);
+        result
+    }
+}
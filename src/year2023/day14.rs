@@ -0,0 +1,139 @@
+use std::fmt::{self, Write};
+
+use crate::{
+    debug,
+    memo::Memo,
+    runner::Answer,
+    trace::{self, Level},
+};
+
+pub fn part1(input: &str) -> Answer {
+    let mut grid = Grid::from_str(input);
+    grid.model_fall();
+    trace::render("day14", Level::Debug, |out| grid.write_grid(out));
+
+    grid.get_total_scores().into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let mut grid = Grid::from_str(input);
+    grid.model_cycle();
+
+    grid.get_total_scores().into()
+}
+
+#[derive(Clone)]
+struct Grid {
+    grid: Vec<Vec<char>>,
+}
+
+impl Grid {
+    fn write_grid(&self, out: &mut impl Write) -> fmt::Result {
+        for g in self.grid.iter() {
+            for c in g {
+                out.write_char(*c)?;
+            }
+            writeln!(out)?;
+        }
+        Ok(())
+    }
+
+    fn from_str(s: &str) -> Self {
+        let grid = s.lines().map(|l| l.chars().collect()).collect();
+        Self { grid }
+    }
+
+    fn model_fall(&mut self) {
+        // Have to do this len times to model all drops correctly..
+        for i in (0..self.grid.len()).rev() {
+            for k in 0..=i {
+                if k > 0 {
+                    for j in 0..self.grid[k].len() {
+                        if self.grid[k][j] == 'O' && self.grid[k - 1][j] == '.' {
+                            self.grid[k][j] = '.';
+                            self.grid[k - 1][j] = 'O';
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // Rotates 90 degrees clockwise so we can model the cycles: n -> w > s > e
+    fn rotate(&mut self) {
+        let mut new_grid = vec![vec![' '; self.grid[0].len()]; self.grid.len()];
+        let row_len = self.grid[0].len();
+        for i in 0..self.grid.len() {
+            for (j, _) in self.grid[0].iter().enumerate() {
+                // A rotation is swapping rows and cols.
+                new_grid[j][row_len - i - 1] = self.grid[i][j];
+            }
+        }
+        self.grid = new_grid;
+    }
+
+    fn model_cycle(&mut self) {
+        // Grid -> the cycle it was first seen after.
+        let mut seen = Memo::<Vec<Vec<char>>, usize>::new();
+        let mut current_cycle_num = 0;
+        loop {
+            for _ in 0..4 {
+                self.model_fall();
+                self.rotate();
+            }
+            current_cycle_num += 1;
+            if let Some(first_seen) = seen.get(&self.grid) {
+                // diff is the cycle length.
+                let diff = current_cycle_num - first_seen;
+                // if this is the one that would be the 1000000000th then break out.
+                if (1000000000 - current_cycle_num) % diff == 0 {
+                    break;
+                }
+            } else {
+                seen.insert(self.grid.clone(), current_cycle_num);
+            }
+        }
+        debug!("day14", "grids seen: {}", seen.stats());
+    }
+
+    fn get_total_scores(&self) -> u64 {
+        self.grid
+            .iter()
+            .rev()
+            .enumerate()
+            .flat_map(|(i, row)| {
+                row.iter().map(move |c| match c {
+                    'O' => (i + 1) as u64,
+                    _ => 0,
+                })
+            })
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::snapshot::assert_snapshot;
+
+    #[test]
+    fn test_write_grid() {
+        let mut grid = Grid::from_str(
+            "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....",
+        );
+        grid.model_fall();
+        assert_eq!(grid.get_total_scores(), 136);
+        let mut out = String::new();
+        grid.write_grid(&mut out).unwrap();
+        assert_snapshot("2023/day14-tilted.txt", &out);
+    }
+}
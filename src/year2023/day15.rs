@@ -0,0 +1,134 @@
+use std::io::{self, BufRead};
+
+use crate::{runner::Answer, stream};
+
+pub fn part1(input: &str) -> Answer {
+    stream::solve_str(part1_streaming, input)
+}
+
+pub fn part2(input: &str) -> Answer {
+    stream::solve_str(part2_streaming, input)
+}
+
+// The input is one long line of steps, read a step at a time.
+pub fn part1_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let mut steps = stream::records(input, b',');
+    let mut part1: u64 = 0;
+    while let Some(step) = steps.next_str()? {
+        part1 += hash(step);
+    }
+    Ok(part1.into())
+}
+
+pub fn part2_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let mut steps = stream::records(input, b',');
+    let mut part2_hashmap = ScuffedHashMap::new();
+    while let Some(step) = steps.next_str()? {
+        part2_hashmap.do_instruction(Instruction::from_str(step))
+    }
+
+    Ok(part2_hashmap.get_score().into())
+}
+
+fn hash(s: &str) -> u64 {
+    let mut value = 0;
+    for c in s.chars() {
+        if !c.is_ascii() {
+            panic!("unexpected char")
+        }
+        value += c as u64;
+        value *= 17;
+        value %= 256;
+    }
+    value
+}
+
+struct Instruction {
+    label: String,
+    op: Operation,
+    value: u64,
+}
+
+impl Instruction {
+    fn from_str(s: &str) -> Self {
+        if s.ends_with('-') {
+            Self {
+                label: s[0..s.len() - 1].to_string(),
+                op: Operation::Remove,
+                value: 0,
+            }
+        } else {
+            let (label, value) = s.split_once('=').unwrap();
+            Self {
+                label: label.to_string(),
+                op: Operation::Set,
+                value: value.parse().unwrap(),
+            }
+        }
+    }
+}
+
+enum Operation {
+    Set,
+    Remove,
+}
+
+struct ScuffedHashMap {
+    boxes: [Vec<Lens>; 256],
+}
+
+#[derive(Clone, Debug)]
+struct Lens {
+    label: String,
+    value: u64,
+}
+
+impl ScuffedHashMap {
+    fn new() -> Self {
+        let boxes: [Vec<Lens>; 256] = vec![vec![]; 256].try_into().unwrap();
+        Self { boxes }
+    }
+
+    fn do_instruction(&mut self, instruction: Instruction) {
+        let lens = Lens {
+            label: instruction.label.clone(),
+            value: instruction.value,
+        };
+        let box_num = hash(&instruction.label) as usize;
+        let b = &mut self.boxes[box_num];
+        match instruction.op {
+            Operation::Set => {
+                let mut has_found = false;
+                for l in &mut *b {
+                    if l.label == lens.label {
+                        *l = lens.clone();
+                        has_found = true;
+                        break;
+                    }
+                }
+                if !has_found {
+                    b.push(lens)
+                }
+            }
+            Operation::Remove => {
+                for i in 0..b.len() {
+                    if b[i].label == lens.label {
+                        b.remove(i);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    fn get_score(&self) -> u64 {
+        let mut total = 0;
+        for (box_index, b) in self.boxes.iter().enumerate() {
+            for (lens_index, lens) in b.iter().enumerate() {
+                let focal_power = (box_index as u64 + 1) * (lens_index as u64 + 1) * lens.value;
+                total += focal_power;
+            }
+        }
+        total
+    }
+}
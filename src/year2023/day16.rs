@@ -0,0 +1,292 @@
+use std::{
+    collections::HashSet,
+    fmt::{self, Write},
+};
+
+use crate::{
+    runner::Answer,
+    trace::{self, Level},
+};
+use itertools::Itertools;
+
+pub fn part1(input: &str) -> Answer {
+    let grid = parse_grid(input);
+    let mut grid_clone_part1 = grid.clone();
+
+    let mut visited = HashSet::<((usize, usize), Direction)>::new();
+    light_traversal(
+        (0, 0),
+        Direction::Right,
+        &mut grid_clone_part1,
+        &mut visited,
+    );
+
+    trace::render("day16", Level::Debug, |out| {
+        debug_print(out, &grid_clone_part1)
+    });
+    let part1: u64 = energised_count(&grid_clone_part1);
+    part1.into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let grid = parse_grid(input);
+    let mut highest = 0;
+    for start_y in 0..grid.len() {
+        {
+            let mut grid_clone = grid.clone();
+            let mut visited = HashSet::<((usize, usize), Direction)>::new();
+            light_traversal(
+                (start_y, 0),
+                Direction::Right,
+                &mut grid_clone,
+                &mut visited,
+            );
+            let score = energised_count(&grid_clone);
+            if score > highest {
+                highest = score
+            }
+        }
+
+        {
+            let mut grid_clone = grid.clone();
+            let mut visited = HashSet::<((usize, usize), Direction)>::new();
+            light_traversal(
+                (start_y, grid[0].len() - 1),
+                Direction::Left,
+                &mut grid_clone,
+                &mut visited,
+            );
+            let score = energised_count(&grid_clone);
+            if score > highest {
+                highest = score
+            }
+        }
+    }
+
+    for start_x in 0..grid[0].len() {
+        {
+            let mut grid_clone = grid.clone();
+            let mut visited = HashSet::<((usize, usize), Direction)>::new();
+            light_traversal((0, start_x), Direction::Down, &mut grid_clone, &mut visited);
+
+            let score = energised_count(&grid_clone);
+            if score > highest {
+                highest = score
+            }
+        }
+
+        {
+            let mut grid_clone = grid.clone();
+            let mut visited = HashSet::<((usize, usize), Direction)>::new();
+            light_traversal(
+                (grid.len() - 1, start_x),
+                Direction::Up,
+                &mut grid_clone,
+                &mut visited,
+            );
+            let score = energised_count(&grid_clone);
+            if score > highest {
+                highest = score
+            }
+        }
+    }
+
+    highest.into()
+}
+
+// Each tile alongside how many times a beam has passed through it.
+fn parse_grid(input: &str) -> Vec<Vec<(char, u64)>> {
+    input
+        .lines()
+        .map(|l| l.chars().zip([0].repeat(l.len())).collect())
+        .collect_vec()
+}
+
+#[allow(unused)]
+fn debug_print2(out: &mut impl Write, grid: &[Vec<(char, u64)>]) -> fmt::Result {
+    for row in grid {
+        for cell in row {
+            out.write_char(cell.0)?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+// How many times the light passed through each tile.
+fn debug_print(out: &mut impl Write, grid: &[Vec<(char, u64)>]) -> fmt::Result {
+    for row in grid {
+        for cell in row {
+            if cell.1 > 0 {
+                write!(out, "{}", cell.1)?;
+            } else {
+                out.write_char('.')?;
+            }
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+fn energised_count(grid: &[Vec<(char, u64)>]) -> u64 {
+    grid.iter()
+        .flatten()
+        .map(|pos| if pos.1 > 0 { 1 } else { 0 })
+        .sum()
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Hash)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+fn light_traversal(
+    start: (usize, usize),
+    direction: Direction,
+    grid: &mut [Vec<(char, u64)>],
+    visited: &mut HashSet<((usize, usize), Direction)>,
+) {
+    let mut next = start;
+    let mut dir = direction;
+    while next.0 < grid.len() && next.1 < grid[0].len() {
+        // If a beam of light has already gone through a point with the same direction, then exit
+        if visited.contains(&(next, dir)) {
+            return;
+        }
+        // Energise the grid
+        visited.insert((next, dir));
+        grid[next.0][next.1].1 += 1;
+
+        match (dir, grid[next.0][next.1].0) {
+            (Direction::Up, '.') | (Direction::Up, '|') => {
+                if next.0 > 0 {
+                    next = (next.0 - 1, next.1);
+                } else {
+                    return;
+                }
+            }
+            (Direction::Up, '/') => {
+                next = (next.0, next.1 + 1);
+                dir = Direction::Right;
+            }
+            (Direction::Up, '\\') => {
+                if next.1 > 0 {
+                    next = (next.0, next.1 - 1);
+                    dir = Direction::Left;
+                } else {
+                    return;
+                }
+            }
+            (Direction::Up, '-') => {
+                if next.1 > 0 {
+                    light_traversal((next.0, next.1 - 1), Direction::Left, grid, visited);
+                }
+                next = (next.0, next.1 + 1);
+                dir = Direction::Right;
+            }
+
+            (Direction::Down, '.') | (Direction::Down, '|') => {
+                next = (next.0 + 1, next.1);
+            }
+            (Direction::Down, '/') => {
+                if next.1 > 0 {
+                    next = (next.0, next.1 - 1);
+                    dir = Direction::Left;
+                } else {
+                    return;
+                }
+            }
+            (Direction::Down, '\\') => {
+                next = (next.0, next.1 + 1);
+                dir = Direction::Right;
+            }
+            (Direction::Down, '-') => {
+                if next.1 > 0 {
+                    light_traversal((next.0, next.1 - 1), Direction::Left, grid, visited);
+                }
+                next = (next.0, next.1 + 1);
+                dir = Direction::Right;
+            }
+
+            (Direction::Right, '.') | (Direction::Right, '-') => {
+                next = (next.0, next.1 + 1);
+            }
+            (Direction::Right, '/') => {
+                if next.0 > 0 {
+                    next = (next.0 - 1, next.1);
+                    dir = Direction::Up;
+                } else {
+                    return;
+                }
+            }
+            (Direction::Right, '\\') => {
+                next = (next.0 + 1, next.1);
+                dir = Direction::Down;
+            }
+            (Direction::Right, '|') => {
+                if next.0 > 0 {
+                    light_traversal((next.0 - 1, next.1), Direction::Up, grid, visited);
+                }
+                next = (next.0 + 1, next.1);
+                dir = Direction::Down;
+            }
+
+            (Direction::Left, '.') | (Direction::Left, '-') => {
+                if next.1 > 0 {
+                    next = (next.0, next.1 - 1);
+                } else {
+                    return;
+                }
+            }
+            (Direction::Left, '/') => {
+                next = (next.0 + 1, next.1);
+                dir = Direction::Down;
+            }
+            (Direction::Left, '\\') => {
+                if next.0 > 0 {
+                    next = (next.0 - 1, next.1);
+                    dir = Direction::Up;
+                } else {
+                    return;
+                }
+            }
+            (Direction::Left, '|') => {
+                if next.0 > 0 {
+                    light_traversal((next.0 - 1, next.1), Direction::Up, grid, visited);
+                }
+                next = (next.0 + 1, next.1);
+                dir = Direction::Down;
+            }
+            (_, _) => panic!("unexpected char"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::snapshot::assert_snapshot;
+
+    #[test]
+    fn test_debug_print() {
+        let input = r".|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....";
+        let mut grid = parse_grid(input);
+        light_traversal((0, 0), Direction::Right, &mut grid, &mut HashSet::new());
+        assert_eq!(energised_count(&grid), 46);
+        let mut out = String::new();
+        debug_print(&mut out, &grid).unwrap();
+        assert_snapshot("2023/day16-energised.txt", &out);
+    }
+}
@@ -0,0 +1,480 @@
+use std::{
+    collections::{HashSet, VecDeque},
+    fmt::{self, Write},
+};
+
+use crate::{runner::Answer, trace, trace::Level};
+use itertools::Itertools;
+
+pub fn part1(input: &str) -> Answer {
+    // A* Priority queue == current heat.
+    search(&parse_grid(input)).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let grid = parse_grid(input);
+    let best = search_with_ultra_crucible(&grid);
+    trace::render("day17", Level::Debug, |out| {
+        print_path_taken(out, &grid, &best.path)
+    });
+    best.total_heat_loss.into()
+}
+
+fn parse_grid(input: &str) -> Vec<Vec<u32>> {
+    input
+        .lines()
+        .map(|l| l.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect()
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct SearchState {
+    last_3_moves: [Direction; 3],
+    current_coord: (usize, usize),
+    path: Vec<(usize, usize)>,
+    total_heat_loss: u32,
+}
+
+impl SearchState {
+    fn push_direction(&mut self, direction: Direction) {
+        self.last_3_moves[0] = self.last_3_moves[1];
+        self.last_3_moves[1] = self.last_3_moves[2];
+        self.last_3_moves[2] = direction;
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+enum Direction {
+    U,
+    D,
+    L,
+    R,
+    Unspecified,
+}
+
+fn search(grid: &[Vec<u32>]) -> u32 {
+    let end = (grid.len() - 1, grid[0].len() - 1);
+
+    let mut priority_queue = VecDeque::<SearchState>::new();
+    // Cache optimisation -> If we're at a coordinate with the same last 3 directions - we are very likely in a cycle
+    // of some kind - so use this to break out of loops.
+    let mut seen_coords_with_paths = HashSet::<((usize, usize), [Direction; 3])>::new();
+
+    let initial_search = SearchState {
+        last_3_moves: [Direction::Unspecified; 3],
+        current_coord: (0, 0),
+        path: vec![(0, 0)],
+        total_heat_loss: 0,
+    };
+    priority_queue.push_front(initial_search);
+
+    while let Some(current_state) = priority_queue.pop_front() {
+        trace!(
+            "day17",
+            "at {:?} heat loss {} last moves {:?}, frontier: {}",
+            current_state.current_coord,
+            current_state.total_heat_loss,
+            current_state.last_3_moves,
+            priority_queue.len()
+        );
+        if current_state.current_coord == end {
+            return current_state.total_heat_loss;
+        }
+        if seen_coords_with_paths
+            .contains(&(current_state.current_coord, current_state.last_3_moves))
+        {
+            continue;
+        }
+
+        // Put adjacent coords onto the queue
+        // Handle directions
+        // Get the current direction we are facing - we are only allowed to continue straight
+        // or rotate.
+        let mut next_directions = HashSet::<Direction>::new();
+        match current_state.last_3_moves[2] {
+            Direction::U => {
+                next_directions.insert(Direction::U);
+                next_directions.insert(Direction::L);
+                next_directions.insert(Direction::R);
+            }
+            Direction::D => {
+                next_directions.insert(Direction::D);
+                next_directions.insert(Direction::L);
+                next_directions.insert(Direction::R);
+            }
+            Direction::L => {
+                next_directions.insert(Direction::U);
+                next_directions.insert(Direction::D);
+                next_directions.insert(Direction::L);
+            }
+            Direction::R => {
+                next_directions.insert(Direction::U);
+                next_directions.insert(Direction::D);
+                next_directions.insert(Direction::R);
+            }
+            Direction::Unspecified => {
+                // this is the starting position - so only down and right are valid
+                next_directions.insert(Direction::D);
+                next_directions.insert(Direction::R);
+            }
+        }
+        if current_state
+            .last_3_moves
+            .iter()
+            .all(|d| *d == Direction::D)
+        {
+            next_directions.remove(&Direction::D);
+        }
+        if current_state
+            .last_3_moves
+            .iter()
+            .all(|d| *d == Direction::U)
+        {
+            next_directions.remove(&Direction::U);
+        }
+        if current_state
+            .last_3_moves
+            .iter()
+            .all(|d| *d == Direction::L)
+        {
+            next_directions.remove(&Direction::L);
+        }
+        if current_state
+            .last_3_moves
+            .iter()
+            .all(|d| *d == Direction::R)
+        {
+            next_directions.remove(&Direction::R);
+        }
+
+        // Handle the new directions:
+        for d in next_directions {
+            let mut next_state = current_state.clone();
+            let new_coord = match d {
+                Direction::U => {
+                    if current_state.current_coord.0 == 0 {
+                        continue;
+                    } else {
+                        (
+                            current_state.current_coord.0 - 1,
+                            current_state.current_coord.1,
+                        )
+                    }
+                }
+                Direction::D => {
+                    if current_state.current_coord.0 == grid.len() - 1 {
+                        continue;
+                    } else {
+                        (
+                            current_state.current_coord.0 + 1,
+                            current_state.current_coord.1,
+                        )
+                    }
+                }
+                Direction::L => {
+                    if current_state.current_coord.1 == 0 {
+                        continue;
+                    } else {
+                        (
+                            current_state.current_coord.0,
+                            current_state.current_coord.1 - 1,
+                        )
+                    }
+                }
+                Direction::R => {
+                    if current_state.current_coord.1 == grid[0].len() - 1 {
+                        continue;
+                    } else {
+                        (
+                            current_state.current_coord.0,
+                            current_state.current_coord.1 + 1,
+                        )
+                    }
+                }
+                Direction::Unspecified => panic!("next direction should not be unspecified"),
+            };
+            next_state.current_coord = new_coord;
+            next_state.path.push(new_coord);
+            next_state.push_direction(d);
+            next_state.total_heat_loss +=
+                grid[next_state.current_coord.0][next_state.current_coord.1];
+            push_with_priority(&mut priority_queue, next_state);
+        }
+
+        seen_coords_with_paths.insert((current_state.current_coord, current_state.last_3_moves));
+    }
+
+    panic!("Did not find the end of grid.")
+}
+
+fn push_with_priority(queue: &mut VecDeque<SearchState>, new: SearchState) {
+    match queue
+        .iter()
+        .position(|state| state.total_heat_loss > new.total_heat_loss)
+    {
+        Some(i) => {
+            if i == 0 {
+                queue.push_front(new)
+            } else {
+                queue.insert(i - 1, new)
+            }
+        }
+        None => queue.push_back(new),
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+struct SearchStateUltraCrucible {
+    last_10_moves: [Direction; 10],
+    current_coord: (usize, usize),
+    path: Vec<(usize, usize)>,
+    total_heat_loss: u32,
+}
+
+impl SearchStateUltraCrucible {
+    fn push_direction(&mut self, direction: Direction) {
+        for i in 0..=8 {
+            self.last_10_moves[i] = self.last_10_moves[i + 1];
+        }
+        self.last_10_moves[9] = direction;
+    }
+}
+
+// The state that reached the end with the least heat loss.
+fn search_with_ultra_crucible(grid: &[Vec<u32>]) -> SearchStateUltraCrucible {
+    let end = (grid.len() - 1, grid[0].len() - 1);
+
+    let mut priority_queue = VecDeque::<SearchStateUltraCrucible>::new();
+    // Cache optimisation -> If we're at a coordinate with the same last directions - we are very likely in a cycle
+    // of some kind - so use this to break out of loops.
+    let mut seen_coords_with_paths = HashSet::<((usize, usize), [Direction; 10])>::new();
+
+    let initial_search = SearchStateUltraCrucible {
+        last_10_moves: [Direction::Unspecified; 10],
+        current_coord: (0, 0),
+        path: vec![(0, 0)],
+        total_heat_loss: 0,
+    };
+    priority_queue.push_front(initial_search);
+
+    while let Some(current_state) = priority_queue.pop_front() {
+        if current_state.current_coord == end
+            && current_state.last_10_moves[6..=9].iter().all_equal()
+        {
+            return current_state;
+        }
+        trace!(
+            "day17",
+            "at {:?} heat loss {} last moves {:?}, frontier: {}",
+            current_state.current_coord,
+            current_state.total_heat_loss,
+            current_state.last_10_moves,
+            priority_queue.len()
+        );
+        if seen_coords_with_paths
+            .contains(&(current_state.current_coord, current_state.last_10_moves))
+        {
+            continue;
+        }
+
+        // Same rules as before - except we how have to have a seqeunce of 4 before we are allowed to turn.
+        let mut next_directions = HashSet::<Direction>::new();
+        match current_state.last_10_moves[9] {
+            Direction::U => {
+                next_directions.insert(Direction::U);
+                if current_state.last_10_moves[6..=9]
+                    .iter()
+                    .all(|d| *d == Direction::U)
+                {
+                    next_directions.insert(Direction::L);
+                    next_directions.insert(Direction::R);
+                }
+            }
+            Direction::D => {
+                next_directions.insert(Direction::D);
+                if current_state.last_10_moves[6..=9]
+                    .iter()
+                    .all(|d| *d == Direction::D)
+                {
+                    next_directions.insert(Direction::L);
+                    next_directions.insert(Direction::R);
+                }
+            }
+            Direction::L => {
+                next_directions.insert(Direction::L);
+
+                if current_state.last_10_moves[6..=9]
+                    .iter()
+                    .all(|d| *d == Direction::L)
+                {
+                    next_directions.insert(Direction::U);
+                    next_directions.insert(Direction::D);
+                }
+            }
+            Direction::R => {
+                next_directions.insert(Direction::R);
+
+                if current_state.last_10_moves[6..=9]
+                    .iter()
+                    .all(|d| *d == Direction::R)
+                {
+                    next_directions.insert(Direction::U);
+                    next_directions.insert(Direction::D);
+                }
+            }
+            Direction::Unspecified => {
+                // this is the starting position - so only down and right are valid
+                next_directions.insert(Direction::D);
+                next_directions.insert(Direction::R);
+            }
+        }
+        if current_state
+            .last_10_moves
+            .iter()
+            .all(|d| *d == Direction::D)
+        {
+            next_directions.remove(&Direction::D);
+        }
+        if current_state
+            .last_10_moves
+            .iter()
+            .all(|d| *d == Direction::U)
+        {
+            next_directions.remove(&Direction::U);
+        }
+        if current_state
+            .last_10_moves
+            .iter()
+            .all(|d| *d == Direction::L)
+        {
+            next_directions.remove(&Direction::L);
+        }
+        if current_state
+            .last_10_moves
+            .iter()
+            .all(|d| *d == Direction::R)
+        {
+            next_directions.remove(&Direction::R);
+        }
+
+        // Handle the new directions:
+        for d in next_directions {
+            let mut next_state = current_state.clone();
+            let new_coord = match d {
+                Direction::U => {
+                    if current_state.current_coord.0 == 0 {
+                        continue;
+                    } else {
+                        (
+                            current_state.current_coord.0 - 1,
+                            current_state.current_coord.1,
+                        )
+                    }
+                }
+                Direction::D => {
+                    if current_state.current_coord.0 == grid.len() - 1 {
+                        continue;
+                    } else {
+                        (
+                            current_state.current_coord.0 + 1,
+                            current_state.current_coord.1,
+                        )
+                    }
+                }
+                Direction::L => {
+                    if current_state.current_coord.1 == 0 {
+                        continue;
+                    } else {
+                        (
+                            current_state.current_coord.0,
+                            current_state.current_coord.1 - 1,
+                        )
+                    }
+                }
+                Direction::R => {
+                    if current_state.current_coord.1 == grid[0].len() - 1 {
+                        continue;
+                    } else {
+                        (
+                            current_state.current_coord.0,
+                            current_state.current_coord.1 + 1,
+                        )
+                    }
+                }
+                Direction::Unspecified => panic!("next direction should not be unspecified"),
+            };
+            next_state.current_coord = new_coord;
+            next_state.path.push(new_coord);
+            next_state.push_direction(d);
+            next_state.total_heat_loss += grid[new_coord.0][new_coord.1];
+            push_with_priority_ultra_crucible(&mut priority_queue, next_state);
+        }
+
+        seen_coords_with_paths.insert((current_state.current_coord, current_state.last_10_moves));
+    }
+
+    panic!("Did not find the end of grid.")
+}
+
+fn push_with_priority_ultra_crucible(
+    queue: &mut VecDeque<SearchStateUltraCrucible>,
+    new: SearchStateUltraCrucible,
+) {
+    match queue
+        .iter()
+        .position(|state| state.total_heat_loss > new.total_heat_loss)
+    {
+        Some(i) => {
+            if i == 0 {
+                queue.push_front(new)
+            } else {
+                queue.insert(i - 1, new)
+            }
+        }
+        None => queue.push_back(new),
+    }
+}
+
+fn print_path_taken(
+    out: &mut impl Write,
+    grid: &[Vec<u32>],
+    path: &[(usize, usize)],
+) -> fmt::Result {
+    for y in 0..grid.len() {
+        for x in 0..grid[0].len() {
+            out.write_char(if path.contains(&(y, x)) { 'X' } else { '.' })?;
+        }
+        writeln!(out)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::snapshot::assert_snapshot;
+
+    #[test]
+    fn test_print_path_taken() {
+        let grid = parse_grid(
+            "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533",
+        );
+        let best = search_with_ultra_crucible(&grid);
+        assert_eq!(best.total_heat_loss, 94);
+        let mut out = String::new();
+        print_path_taken(&mut out, &grid, &best.path).unwrap();
+        assert_snapshot("2023/day17-path.txt", &out);
+    }
+}
@@ -0,0 +1,130 @@
+use std::io::{self, BufRead};
+
+use itertools::Itertools;
+
+use crate::{runner::Answer, stream};
+
+pub fn part1(input: &str) -> Answer {
+    stream::solve_str(part1_streaming, input)
+}
+
+pub fn part2(input: &str) -> Answer {
+    stream::solve_str(part2_streaming, input)
+}
+
+// Part 2 - > completely fucks the model for p1 so this got re-written.
+// After some googling: https://en.wikipedia.org/wiki/Pick%27s_theorem + https://en.wikipedia.org/wiki/Shoelace_formula
+pub fn part1_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let mut lines = stream::lines(input);
+    let mut lagoon = Lagoon::default();
+    while let Some(line) = lines.next_str()? {
+        lagoon.dig(&Instruction::from_str(line));
+    }
+    Ok(lagoon.area().into())
+}
+
+pub fn part2_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let mut lines = stream::lines(input);
+    let mut lagoon = Lagoon::default();
+    while let Some(line) = lines.next_str()? {
+        lagoon.dig(&Instruction::from_str(line).real_instruction());
+    }
+    Ok(lagoon.area().into())
+}
+
+// Picks theorem is:
+// Area = Sum of Interior + (Sum of boundary points)/2 - 1;
+// Shoelace gets you a way to get area.
+// The problem asks you to count the total number of points inside and on the boundary ( which is the perimeter value).
+// Area + 1 = sum of interior + (sum of boundary) / 2
+// Area + 1 + sum of boundary / 2 = sum of interior + sum of boundary = solution.
+//
+// Both sums are kept as the trench is dug, starting and ending at (0, 0), so the corners
+// needn't be.
+#[derive(Default)]
+struct Lagoon {
+    current_vertex: (i64, i64),
+    sum_of_determinants: i64,
+    perimeter_length: u64,
+}
+
+impl Lagoon {
+    fn dig(&mut self, inst: &Instruction) {
+        let mut next_vertex = self.current_vertex;
+        match inst.direction {
+            Direction::U => next_vertex.0 += (inst.amount) as i64,
+            Direction::D => next_vertex.0 -= (inst.amount) as i64,
+            Direction::L => next_vertex.1 -= (inst.amount) as i64,
+            Direction::R => next_vertex.1 += (inst.amount) as i64,
+        }
+        // Shoelace
+        self.sum_of_determinants +=
+            (self.current_vertex.0 * next_vertex.1) - (self.current_vertex.1 * next_vertex.0);
+        self.perimeter_length += inst.amount;
+        self.current_vertex = next_vertex;
+    }
+
+    fn area(&self) -> u64 {
+        // Make sure this area is a whole number since our coordinates are only integers.
+        debug_assert!((self.sum_of_determinants % 2) == 0);
+        let area: u64 = (self.sum_of_determinants.abs() / 2).try_into().unwrap();
+
+        area + self.perimeter_length / 2 + 1
+    }
+}
+
+struct Instruction {
+    direction: Direction,
+    amount: u64,
+    hex: String,
+}
+
+impl Instruction {
+    fn from_str(s: &str) -> Self {
+        let (direction_str, amount_str, hex_str) = s.splitn(3, ' ').collect_tuple().unwrap();
+
+        Self {
+            direction: Direction::from_str(direction_str),
+            amount: amount_str.parse().unwrap(),
+            hex: hex_str[2..hex_str.len() - 1].to_string(),
+        }
+    }
+
+    // The instruction hidden in the colour, for part 2.
+    fn real_instruction(&self) -> Self {
+        let hex_str = self.hex.as_str();
+        let direction = match &hex_str[hex_str.len() - 1..hex_str.len()] {
+            "0" => Direction::R,
+            "1" => Direction::D,
+            "2" => Direction::L,
+            "3" => Direction::U,
+            x => panic!("unexpected direction: {}", x),
+        };
+        let amount = u64::from_str_radix(&hex_str[0..hex_str.len() - 1], 16).unwrap();
+
+        Instruction {
+            amount,
+            direction,
+            hex: "".to_string(),
+        }
+    }
+}
+
+enum Direction {
+    U,
+    D,
+    L,
+    R,
+}
+
+impl Direction {
+    fn from_str(s: &str) -> Self {
+        match s {
+            "U" => Self::U,
+            "D" => Self::D,
+            "L" => Self::L,
+            "R" => Self::R,
+            _ => panic!("unexpected str"),
+        }
+    }
+}
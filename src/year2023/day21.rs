@@ -0,0 +1,337 @@
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    arith, info,
+    runner::{Answer, Assumption},
+};
+
+const TOTAL_STEPS: usize = 26501365;
+
+pub const ASSUMPTIONS: &[Assumption] = &[
+    Assumption {
+        name: "the garden is a square",
+        check: garden_is_square,
+    },
+    Assumption {
+        name: "the start is in the centre",
+        check: start_is_in_centre,
+    },
+    Assumption {
+        name: "the centre row and column are clear",
+        check: centre_row_and_column_are_clear,
+    },
+    Assumption {
+        name: "the steps end on the edge of garden 202300",
+        check: steps_end_on_garden_edge,
+    },
+];
+
+pub fn part1(input: &str) -> Answer {
+    let (graph, start) = parse_garden(input);
+
+    // Doing completeDFS/BFS graph traversal is too computationally complex.
+    // Instead consider that each "step" is a fixed amount.
+    // A step forward and back is two steps that result in the same place.
+    // So it is all the locations that are traversable in the max distance,
+    // and even distance from the beginning.
+    let possible_locations = traverse_graph(&graph, 64, start);
+    find_possibilities(&possible_locations, start, 64).into()
+}
+
+pub fn part2(input: &str) -> Answer {
+    let (graph, start) = parse_garden(input);
+
+    // Part 2 needs a geometric solution. No shot for me to get this without help:
+    // https://github.com/villuna/aoc23/wiki/A-Geometric-solution-to-advent-of-code-2023,-day-21
+    let all_possible_locations = traverse_graph(&graph, 131, start);
+    let even_visited_locations = all_possible_locations
+        .iter()
+        .filter(|val| distance(**val, start).is_multiple_of(2))
+        .count();
+    let odd_visited_locations = all_possible_locations
+        .iter()
+        .filter(|val| distance(**val, start) % 2 == 1)
+        .count();
+    let even_locations_from_center = all_possible_locations
+        .iter()
+        .filter(|val| distance(**val, start).is_multiple_of(2) && distance(**val, start) > 65)
+        .count();
+    let odd_locations_from_center = all_possible_locations
+        .iter()
+        .filter(|val| distance(**val, start) % 2 == 1 && distance(**val, start) > 65)
+        .count();
+
+    info!(
+        "day21",
+        "total odd: {}, total even:{}, odd_corners: {}, even_corners: {}",
+        odd_visited_locations,
+        even_visited_locations,
+        odd_locations_from_center,
+        even_locations_from_center
+    );
+
+    let n = (TOTAL_STEPS - (graph.len() / 2)) / graph.len();
+    assert_eq!(n, 202300);
+
+    // Brute force low values to make sure the answers are equal.
+    // let possible_locations2 = traverse_graph_infinity(&graph, TOTAL_STEPS, start);
+    // let part2_brute = find_possibilities2(&possible_locations2, (start.0 as i64, start.1 as i64), TOTAL_STEPS as i64);
+    // println!("part2 brute: {}", part2_brute);
+
+    let what = "day21 part2";
+    let full_gardens = arith::add(
+        what,
+        arith::product(what, [n + 1, n + 1, odd_visited_locations]),
+        arith::product(what, [n, n, even_visited_locations]),
+    );
+    let part2 = arith::add(
+        what,
+        arith::sub(
+            what,
+            full_gardens,
+            arith::mul(what, n + 1, odd_locations_from_center),
+        ),
+        arith::mul(what, n, even_locations_from_center),
+    );
+    part2.into()
+}
+
+fn parse_garden(input: &str) -> (Vec<Vec<char>>, (usize, usize)) {
+    let graph: Vec<Vec<char>> = input.lines().map(|l| l.chars().collect()).collect();
+    let mut start = (0, 0);
+    for (y, _) in graph.iter().enumerate() {
+        for (x, _) in graph[y].iter().enumerate() {
+            if graph[y][x] == 'S' {
+                start = (y, x);
+                break;
+            }
+        }
+    }
+
+    (graph, start)
+}
+
+// The geometric solution for part 2 tiles copies of the garden in a diamond, which relies on the
+// shape of the input.
+fn garden_is_square(input: &str) -> Result<(), String> {
+    let (graph, _) = parse_garden(input);
+    if let Some((y, line)) = graph
+        .iter()
+        .enumerate()
+        .find(|(_, line)| line.len() != graph.len())
+    {
+        return Err(format!(
+            "{} rows, but row {} is {} wide",
+            graph.len(),
+            y + 1,
+            line.len()
+        ));
+    }
+    if graph.len().is_multiple_of(2) {
+        return Err(format!(
+            "the garden is {0}x{0}, it has no centre",
+            graph.len()
+        ));
+    }
+    Ok(())
+}
+
+fn start_is_in_centre(input: &str) -> Result<(), String> {
+    garden_is_square(input)?;
+    let (graph, start) = parse_garden(input);
+    let centre = (graph.len() / 2, graph.len() / 2);
+    if graph[start.0][start.1] != 'S' {
+        return Err("there is no start".to_string());
+    }
+    if start != centre {
+        return Err(format!("the start is at {:?}, not {:?}", start, centre));
+    }
+    Ok(())
+}
+
+// Needed so the straight line out to the far gardens is as short as the manhattan distance.
+fn centre_row_and_column_are_clear(input: &str) -> Result<(), String> {
+    start_is_in_centre(input)?;
+    let (graph, start) = parse_garden(input);
+    for (i, row) in graph.iter().enumerate() {
+        if graph[start.0][i] == '#' {
+            return Err(format!("rock in the centre row at column {}", i + 1));
+        }
+        if row[start.1] == '#' {
+            return Err(format!("rock in the centre column at row {}", i + 1));
+        }
+    }
+    Ok(())
+}
+
+fn steps_end_on_garden_edge(input: &str) -> Result<(), String> {
+    garden_is_square(input)?;
+    let (graph, _) = parse_garden(input);
+    let size = graph.len();
+    if TOTAL_STEPS < size / 2 || !(TOTAL_STEPS - size / 2).is_multiple_of(size) {
+        return Err(format!(
+            "{} steps from the centre don't end on the edge of a {}x{} garden",
+            TOTAL_STEPS, size, size
+        ));
+    }
+    let n = (TOTAL_STEPS - size / 2) / size;
+    if n != 202300 {
+        return Err(format!("the steps reach garden {}, not 202300", n));
+    }
+    Ok(())
+}
+
+fn find_possibilities(
+    possible_locations: &HashSet<(usize, usize)>,
+    start: (usize, usize),
+    max_distance: usize,
+) -> u64 {
+    let mut total_possible = 0;
+    for loc in possible_locations {
+        let dist = distance(*loc, start);
+        if dist <= max_distance && (max_distance - dist).is_multiple_of(2) {
+            total_possible += 1;
+        }
+    }
+    total_possible
+}
+
+fn distance(a: (usize, usize), b: (usize, usize)) -> usize {
+    a.0.abs_diff(b.0) + a.1.abs_diff(b.1)
+}
+
+#[allow(unused)]
+fn find_possibilities2(
+    possible_locations: &HashSet<(i64, i64)>,
+    start: (i64, i64),
+    max_distance: i64,
+) -> u64 {
+    let mut total_possible = 0;
+    for loc in possible_locations {
+        let dist = distance2(*loc, start);
+        if dist <= max_distance && (max_distance - dist) % 2 == 0 {
+            total_possible += 1;
+        }
+    }
+    total_possible
+}
+
+#[allow(unused)]
+fn distance2(a: (i64, i64), b: (i64, i64)) -> i64 {
+    (a.0.abs_diff(b.0) + a.1.abs_diff(b.1)).try_into().unwrap()
+}
+
+fn traverse_graph(
+    graph: &[Vec<char>],
+    total_steps: usize,
+    start: (usize, usize),
+) -> HashSet<(usize, usize)> {
+    let mut visited_locations = HashSet::new();
+
+    let mut queue = VecDeque::<(usize, (usize, usize))>::new();
+    queue.push_front((0, start));
+
+    while let Some((step_count, current_location)) = queue.pop_front() {
+        if step_count <= total_steps {
+            // up
+            if current_location.0 > 0 {
+                match graph[current_location.0 - 1][current_location.1] {
+                    'S' | '.' => {
+                        let new_location = (current_location.0 - 1, current_location.1);
+                        if !visited_locations.contains(&new_location) {
+                            visited_locations.insert(new_location);
+                            queue.push_back((step_count + 1, new_location))
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            // down
+            if current_location.0 < graph.len() - 1 {
+                match graph[current_location.0 + 1][current_location.1] {
+                    'S' | '.' => {
+                        let new_location = (current_location.0 + 1, current_location.1);
+                        if !visited_locations.contains(&new_location) {
+                            visited_locations.insert(new_location);
+                            queue.push_back((step_count + 1, new_location))
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            // left
+            if current_location.1 > 0 {
+                match graph[current_location.0][current_location.1 - 1] {
+                    'S' | '.' => {
+                        let new_location = (current_location.0, current_location.1 - 1);
+                        if !visited_locations.contains(&new_location) {
+                            visited_locations.insert(new_location);
+                            queue.push_back((step_count + 1, new_location))
+                        }
+                    }
+                    _ => {}
+                }
+            }
+            // right
+            if current_location.1 < graph[0].len() - 1 {
+                match graph[current_location.0][current_location.1 + 1] {
+                    'S' | '.' => {
+                        let new_location = (current_location.0, current_location.1 + 1);
+                        if !visited_locations.contains(&new_location) {
+                            visited_locations.insert(new_location);
+                            queue.push_back((step_count + 1, new_location))
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    visited_locations
+}
+
+#[allow(unused)]
+fn map_i64_coord_to_usize(c: (i64, i64), y_bound: usize, x_bound: usize) -> (usize, usize) {
+    (
+        c.0.rem_euclid(y_bound as i64) as usize,
+        (c.1.rem_euclid(x_bound as i64) as usize),
+    )
+}
+
+#[allow(unused)]
+fn traverse_graph_infinity(
+    graph: &[Vec<char>],
+    total_steps: usize,
+    start: (usize, usize),
+) -> HashSet<(i64, i64)> {
+    let mut visited_locations = HashSet::new();
+
+    let mut queue = VecDeque::<(usize, (i64, i64))>::new();
+    let new_start = (start.0 as i64, start.1 as i64);
+    queue.push_front((0, new_start));
+
+    while let Some((step_count, current_location)) = queue.pop_front() {
+        if step_count <= total_steps {
+            let mut new_locations = [(0_i64, 0_i64); 4];
+            new_locations[0] = (current_location.0 - 1, current_location.1);
+            new_locations[1] = (current_location.0 + 1, current_location.1);
+            new_locations[2] = (current_location.0, current_location.1 - 1);
+            new_locations[3] = (current_location.0, current_location.1 + 1);
+
+            for new_location in new_locations {
+                let usize_location =
+                    map_i64_coord_to_usize(new_location, graph.len(), graph[0].len());
+                match graph[usize_location.0][usize_location.1] {
+                    'S' | '.' if !visited_locations.contains(&new_location) => {
+                        visited_locations.insert(new_location);
+                        queue.push_back((step_count + 1, new_location))
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    visited_locations
+}
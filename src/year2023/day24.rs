@@ -0,0 +1,489 @@
+use std::io::{self, BufRead};
+
+use itertools::Itertools;
+use num_rational::BigRational;
+use num_traits::{ToPrimitive, Zero};
+#[cfg(feature = "z3")]
+use z3::{
+    ast::{Ast, Int},
+    Config, Context, SatResult, Solver,
+};
+
+use crate::{runner::Answer, stream};
+
+pub fn part1(input: &str) -> Answer {
+    stream::solve_str(part1_streaming, input)
+}
+
+pub fn part2(input: &str) -> Answer {
+    let part2 = stream::solve_str(part2_streaming, input);
+
+    #[cfg(feature = "z3")]
+    {
+        let hailstones: Vec<Hailstone> = input.lines().map(Hailstone::from_input).collect_vec();
+        assert_eq!(part2, part2_z3(&hailstones).into(), "z3 disagrees");
+    }
+
+    part2
+}
+
+// Every pair is compared, so the hailstones are all kept, though not their text.
+pub fn part1_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let mut lines = stream::lines(input);
+    let mut hailstones: Vec<Hailstone> = vec![];
+    while let Some(line) = lines.next_str()? {
+        hailstones.push(Hailstone::from_input(line));
+    }
+
+    let mut total_count = 0;
+    for (i, h1) in hailstones.iter().enumerate() {
+        for (j, h2) in hailstones.iter().enumerate() {
+            // Ignore duplicates
+            if j <= i {
+                continue;
+            }
+
+            if h1.intersects_x_y(h2, 200000000000000, 400000000000000) {
+                total_count += 1;
+            }
+        }
+    }
+
+    Ok(total_count.into())
+}
+
+pub fn part2_streaming(input: &mut dyn BufRead) -> io::Result<Answer> {
+    let mut lines = stream::lines(input);
+    let mut throw = RockThrow::default();
+    while let Some(line) = lines.next_str()? {
+        throw.add(Hailstone::from_input(line));
+    }
+
+    let (start, _) = throw.finish().ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            "no single throw hits every hailstone",
+        )
+    })?;
+    let part2 = start.0 + start.1 + start.2;
+
+    Ok(part2.into())
+}
+
+// The rock starts at P with velocity V and hits hailstone i (start p_i, velocity v_i) at some
+// time t_i, so P - p_i = t_i * (v_i - V). Those are parallel, hence (P - p_i) x (V - v_i) = 0,
+// which expands to
+//   P x V - P x v_i - p_i x V + p_i x v_i = 0.
+// P x V is the same for every hailstone, so subtracting the equations of hailstones i and j
+// leaves something linear in P and V:
+//   P x (v_j - v_i) + (p_j - p_i) x V = p_j x v_j - p_i x v_i
+// Two pairs give 6 equations for the 6 unknowns. The numbers get far too big for i128 along
+// the way, so it is solved exactly with big rationals.
+//
+// The hailstones are added one at a time. Only those read before the throw is pinned down are
+// kept, the rest are just checked against it.
+#[derive(Default)]
+struct RockThrow {
+    hailstones: Vec<Hailstone>,
+    throw: Option<(Vector3, Vector3)>,
+    missed: bool,
+}
+
+impl RockThrow {
+    fn add(&mut self, hailstone: Hailstone) {
+        if self.missed {
+            return;
+        }
+        if let Some((start, velocity)) = self.throw {
+            self.missed = !hailstone.hit_by(start, velocity);
+            return;
+        }
+        self.hailstones.push(hailstone);
+        if self.hailstones.len() < 3 {
+            return;
+        }
+
+        // Any two pairs with hailstone 0 do, unless their equations happen to be dependent.
+        let (h0, h2) = (
+            &self.hailstones[0],
+            &self.hailstones[self.hailstones.len() - 1],
+        );
+        let earlier = &self.hailstones[1..self.hailstones.len() - 1];
+        for h1 in earlier {
+            let mut rows = pair_equations(h0, h1);
+            rows.extend(pair_equations(h0, h2));
+            let Some(solution) = solve_linear(rows) else {
+                continue;
+            };
+            self.throw = integer_throw(&solution).filter(|&(start, velocity)| {
+                self.hailstones.iter().all(|h| h.hit_by(start, velocity))
+            });
+            self.missed = self.throw.is_none();
+            return;
+        }
+    }
+
+    fn finish(self) -> Option<(Vector3, Vector3)> {
+        self.throw.filter(|_| !self.missed)
+    }
+}
+
+// The start and velocity in a solution, if they are whole numbers.
+fn integer_throw(solution: &[BigRational]) -> Option<(Vector3, Vector3)> {
+    if !solution.iter().all(|x| x.is_integer()) {
+        return None;
+    }
+    let values: Vec<i64> = solution
+        .iter()
+        .map(|x| x.to_integer().to_i64())
+        .collect::<Option<_>>()?;
+    let start = (values[0], values[1], values[2]);
+    let velocity = (values[3], values[4], values[5]);
+    Some((start, velocity))
+}
+
+// The 3 rows [P | V | rhs] of P x (v_j - v_i) + (p_j - p_i) x V = p_j x v_j - p_i x v_i.
+fn pair_equations(hi: &Hailstone, hj: &Hailstone) -> Vec<Vec<BigRational>> {
+    let w = sub(hj.diff, hi.diff);
+    let d = sub(hj.start, hi.start);
+    let rhs = sub128(cross(hj.start, hj.diff), cross(hi.start, hi.diff));
+    // P x w and d x V written out as coefficients on P and V.
+    [
+        [0, w.2, -w.1, 0, -d.2, d.1],
+        [-w.2, 0, w.0, d.2, 0, -d.0],
+        [w.1, -w.0, 0, -d.1, d.0, 0],
+    ]
+    .into_iter()
+    .zip([rhs.0, rhs.1, rhs.2])
+    .map(|(coefficients, rhs)| {
+        coefficients
+            .into_iter()
+            .map(|c| BigRational::from_integer(c.into()))
+            .chain([BigRational::from_integer(rhs.into())])
+            .collect()
+    })
+    .collect()
+}
+
+// Gauss-Jordan elimination on the augmented rows. None if the system is singular.
+fn solve_linear(mut rows: Vec<Vec<BigRational>>) -> Option<Vec<BigRational>> {
+    let n = rows.len();
+    for col in 0..n {
+        let pivot = (col..n).find(|&r| !rows[r][col].is_zero())?;
+        rows.swap(col, pivot);
+        let pivot_row = rows[col].clone();
+        for (r, row) in rows.iter_mut().enumerate() {
+            if r == col || row[col].is_zero() {
+                continue;
+            }
+            let factor = &row[col] / &pivot_row[col];
+            for (x, p) in row.iter_mut().zip(&pivot_row) {
+                *x -= &factor * p;
+            }
+        }
+    }
+    Some(
+        rows.iter()
+            .enumerate()
+            .map(|(i, row)| &row[n] / &row[i])
+            .collect(),
+    )
+}
+
+fn sub(a: Vector3, b: Vector3) -> Vector3 {
+    (a.0 - b.0, a.1 - b.1, a.2 - b.2)
+}
+
+fn sub128(a: (i128, i128, i128), b: (i128, i128, i128)) -> (i128, i128, i128) {
+    (a.0 - b.0, a.1 - b.1, a.2 - b.2)
+}
+
+fn cross(a: Vector3, b: Vector3) -> (i128, i128, i128) {
+    let (a, b) = (
+        (a.0 as i128, a.1 as i128, a.2 as i128),
+        (b.0 as i128, b.1 as i128, b.2 as i128),
+    );
+    (
+        a.1 * b.2 - a.2 * b.1,
+        a.2 * b.0 - a.0 * b.2,
+        a.0 * b.1 - a.1 * b.0,
+    )
+}
+
+// The original solution, kept to cross-check the one above when built with the z3 feature.
+#[cfg(feature = "z3")]
+fn part2_z3(hailstones: &[Hailstone]) -> i64 {
+    // Part2 feels extremely silly to do without using a solver like wolfram alpha.
+    // It's 6 simultaneous equations with 6 unknowns (Start (xyz), Velocity(xyz)).
+    // Or 9 if you want to not eliminate the times from the equations. Either way
+    // Using 3 of the inputs is enough to solve the problem:
+    // E.g. slapping
+    // 19 - 2i = x + i*a;  13+i = y + i*b; 30 - 2*i = z + i*c ;  18- j = x + j*a;  19-j = y + j*b; 22 - 2*j  = z + j*c;  20 -2*k = x + k*a;  25-2*k = y + k*b; 34 - 4*k  = z + k*c;
+    // into wolfram alpha gives the solution for the example.
+    // After trying to reduce 2 of the 6 equations by hand I decided I could not be arsed, so decided to throw the un-reduced
+    // forms into a solver instead.
+    // Unfortunately, the actual input has too many characters for the Wolfram alpha to accept :(.
+    // So the options are either to:
+    // Muck around with a solver like z3: https://docs.rs/z3/latest/z3/
+    // Download Wolfram Mathematica and chuck the the equations in.
+    // FWIW the Wolfram input for mine was:
+    // Solve[327367788702047 + 20 i == x + i a &&
+    // 294752664325632 + 51 i == y + i b &&
+    // 162080199489440 + 36 i == z + i c &&
+    // 349323332347395 - 96 j == x + j a &&
+    // 429135322811787 - 480 j == y + j b &&
+    // 397812423558610 - 782 j == z + j c &&
+    // 342928768632768 -69 k == x + k a &&
+    // 275572250031810+ 104 k == y + k b &&
+    // 310926883862869- 510 k  == z + k c, {a,b,c,x,y,z,i,j,k}, Integers]
+
+    // For completeness I messed around enough with Z3's API to get the answer albeit still needing
+    // to use a 3rd party lib:
+    let cfg = Config::new();
+    let ctx = Context::new(&cfg);
+
+    let solver = Solver::new(&ctx);
+    // Our known constants:
+    let point0 = (
+        Int::from_i64(&ctx, hailstones[0].start.0),
+        Int::from_i64(&ctx, hailstones[0].start.1),
+        Int::from_i64(&ctx, hailstones[0].start.2),
+    );
+    let point1 = (
+        Int::from_i64(&ctx, hailstones[1].start.0),
+        Int::from_i64(&ctx, hailstones[1].start.1),
+        Int::from_i64(&ctx, hailstones[1].start.2),
+    );
+    let point2 = (
+        Int::from_i64(&ctx, hailstones[2].start.0),
+        Int::from_i64(&ctx, hailstones[2].start.1),
+        Int::from_i64(&ctx, hailstones[2].start.2),
+    );
+
+    let velocity0 = (
+        Int::from_i64(&ctx, hailstones[0].diff.0),
+        Int::from_i64(&ctx, hailstones[0].diff.1),
+        Int::from_i64(&ctx, hailstones[0].diff.2),
+    );
+
+    let velocity1 = (
+        Int::from_i64(&ctx, hailstones[1].diff.0),
+        Int::from_i64(&ctx, hailstones[1].diff.1),
+        Int::from_i64(&ctx, hailstones[1].diff.2),
+    );
+    let velocity2 = (
+        Int::from_i64(&ctx, hailstones[2].diff.0),
+        Int::from_i64(&ctx, hailstones[2].diff.1),
+        Int::from_i64(&ctx, hailstones[2].diff.2),
+    );
+
+    // Our unknowns:
+    let (t0, t1, t2) = (
+        Int::new_const(&ctx, "t0"),
+        Int::new_const(&ctx, "t1"),
+        Int::new_const(&ctx, "t2"),
+    );
+    let (x, y, z, vx, vy, vz) = (
+        Int::new_const(&ctx, "x"),
+        Int::new_const(&ctx, "y"),
+        Int::new_const(&ctx, "z"),
+        Int::new_const(&ctx, "dx"),
+        Int::new_const(&ctx, "dy"),
+        Int::new_const(&ctx, "dz"),
+    );
+
+    // The equations / constraints;
+    // Time must be > 0;
+    solver.assert(&t0.gt(&Int::from_i64(&ctx, 0)));
+    solver.assert(&t1.gt(&Int::from_i64(&ctx, 0)));
+    solver.assert(&t2.gt(&Int::from_i64(&ctx, 0)));
+
+    // The 9 equations:
+    solver.assert(
+        &(&x.clone() + &vx.clone() * &t0.clone())
+            ._eq(&(&point0.0.clone() + &velocity0.0.clone() * &t0.clone())),
+    );
+    solver.assert(
+        &(&y.clone() + &vy.clone() * &t0.clone())
+            ._eq(&(&point0.1.clone() + &velocity0.1.clone() * &t0.clone())),
+    );
+    solver.assert(
+        &(&z.clone() + &vz.clone() * &t0.clone())
+            ._eq(&(&point0.2.clone() + &velocity0.2.clone() * &t0.clone())),
+    );
+
+    solver.assert(
+        &(&x.clone() + &vx.clone() * &t1.clone())
+            ._eq(&(&point1.0.clone() + &velocity1.0.clone() * &t1.clone())),
+    );
+    solver.assert(
+        &(&y.clone() + &vy.clone() * &t1.clone())
+            ._eq(&(&point1.1.clone() + &velocity1.1.clone() * &t1.clone())),
+    );
+    solver.assert(
+        &(&z.clone() + &vz.clone() * &t1.clone())
+            ._eq(&(&point1.2.clone() + &velocity1.2.clone() * &t1.clone())),
+    );
+
+    solver.assert(
+        &(&x.clone() + &vx.clone() * &t2.clone())
+            ._eq(&(&point2.0.clone() + &velocity2.0.clone() * &t2.clone())),
+    );
+    solver.assert(
+        &(&y.clone() + &vy.clone() * &t2.clone())
+            ._eq(&(&point2.1.clone() + &velocity2.1.clone() * &t2.clone())),
+    );
+    solver.assert(
+        &(&z.clone() + &vz.clone() * &t2.clone())
+            ._eq(&(&point2.2.clone() + &velocity2.2.clone() * &t2.clone())),
+    );
+
+    assert_eq!(solver.check(), SatResult::Sat);
+    let m = solver.get_model().unwrap();
+
+    let x_value = m.eval(&x, true).unwrap().as_i64().unwrap();
+    let y_value = m.eval(&y, true).unwrap().as_i64().unwrap();
+    let z_value = m.eval(&z, true).unwrap().as_i64().unwrap();
+
+    x_value + y_value + z_value
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Hailstone {
+    start: Vector3,
+    diff: Vector3,
+}
+
+impl Hailstone {
+    fn from_input(s: &str) -> Self {
+        // 19, 13, 30 @ -2,  1, -2
+        let (start_str, diff_str) = s.split_once(" @ ").unwrap();
+        let start = start_str
+            .splitn(3, ", ")
+            .map(|s| s.trim().parse::<i64>().unwrap())
+            .collect_tuple()
+            .unwrap();
+        let diff = diff_str
+            .splitn(3, ", ")
+            .map(|s| s.trim().parse::<i64>().unwrap())
+            .collect_tuple()
+            .unwrap();
+
+        Self { start, diff }
+    }
+
+    // Whether a rock thrown from start with velocity ever shares a position with this hailstone,
+    // counting a hit at time 0.
+    fn hit_by(&self, start: Vector3, velocity: Vector3) -> bool {
+        let offset = sub(start, self.start);
+        let closing = sub(self.diff, velocity);
+        if cross(offset, closing) != (0, 0, 0) {
+            return false;
+        }
+        // Parallel, so they meet when offset = t * closing for some t >= 0.
+        let dot = |a: Vector3, b: Vector3| {
+            a.0 as i128 * b.0 as i128 + a.1 as i128 * b.1 as i128 + a.2 as i128 * b.2 as i128
+        };
+        offset == (0, 0, 0) || dot(offset, closing) > 0
+    }
+
+    fn intersects_x_y(&self, other: &Hailstone, lower_bound: i64, upper_bound: i64) -> bool {
+        // Long winded formula:
+        // if a stone has formula xn = cxn + tdxn
+        // yn = xyn + tdyn
+        // where t is time
+        //
+        // Then the two paths of h0, h1 interset of there is a t0 and t1 at which x and y values are equal for h0 and h1. (t0 could be equal to t1)
+        // i.e. we have the following simultaneous equations:
+        // x intersect:
+        // cx0 + t0*dx0
+        // cx1 + t1*dx1
+        // for some values of t1, t0.
+        // x intersect: cx0 + t0*dx0 == x1 = xc1 + t1*dx1
+        // y intersect: cy0 + t0*dy0 == y1 = yc1 + t1*dy1
+        //
+        // Rearranging for t0 and t1 to find the times at which the paths can intersect.
+        // Two things intersect at t0 after some rearranging at:
+        // t0 = (dy1*kx - dx1*ky) / (dx0*dy1 - dx1*dy0)
+        //
+        // where kx = cx1 - xc0
+        // ky = cy1 - cy0
+        // In particular if the velocities are parallel:
+        // dx1 == dx0, dy1 == dy0. Then the divisor is 0 and no intersections occur.
+        // **ALSO assumes velocities are never zero** (as we divide by velocities in the formula rearranging).
+        //
+        // Similarly for the t1 intersect:
+        //
+        // t1 = = (- dy0*kx - dx0*ky) / (dx1*dy0 - dx0*dy1)
+        //
+
+        // Other == 1, this == 0;
+        if other.diff.0 * self.diff.1 - self.diff.0 * other.diff.1 == 0 {
+            // Parallel.
+            return false;
+        }
+        let c_x = other.start.0 - self.start.0;
+        let c_y = other.start.1 - self.start.1;
+
+        let denominator0 = (other.diff.1 * self.diff.0 - self.diff.1 * other.diff.0) as f64;
+        let t_0 = (other.diff.1 * c_x - other.diff.0 * c_y) as f64 / denominator0;
+
+        let (x, y) = (
+            self.start.0 as f64 + self.diff.0 as f64 * t_0,
+            self.start.1 as f64 + self.diff.1 as f64 * t_0,
+        );
+        // Debug assert that this is the same as if we took the formula for t1..
+        let denominator1 = (other.diff.1 * self.diff.0 - self.diff.1 * other.diff.0) as f64;
+        let t_1 = (c_x * self.diff.1 - c_y * self.diff.0) as f64 / denominator1;
+
+        // let (x1, y1) = (
+        //     other.start.0 as f64 + other.diff.0 as f64 * t_1,
+        //     other.start.1 as f64 + other.diff.1 as f64 * t_1,
+        // );
+        // let x_str = format!("{:.3}", x);
+        // let y_str = format!("{:.3}", y);
+        // let x1_str = format!("{:.3}", x1);
+        // let y1_str = format!("{:.3}", y1);
+        // Round up to 3 decimal strings.. This is necessary due to floating point
+        // inprecisions.
+        // debug_assert_eq!((x_str, y_str), (x1_str, y1_str));
+
+        if t_0 < 0.0 || t_1 < 0.0 {
+            return false;
+        }
+
+        if (x >= lower_bound as f64 && x <= upper_bound as f64)
+            && (y >= lower_bound as f64 && y <= upper_bound as f64)
+        {
+            return true;
+        }
+        false
+    }
+}
+
+type Vector3 = (i64, i64, i64);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_part2_example() {
+        let input = "19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3";
+        assert_eq!(part2(input), 47.into());
+
+        // No throw hits a hailstone that is out of line with the rest.
+        let input = format!("{}\n20, 19, 16 @  1, -5, -3", input);
+        let mut throw = RockThrow::default();
+        for line in input.lines() {
+            throw.add(Hailstone::from_input(line));
+        }
+        assert_eq!(throw.finish(), None);
+        let err = part2_streaming(&mut input.as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,151 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    fmt::{self, Write},
+};
+
+use itertools::Itertools;
+
+use crate::{
+    runner::{Answer, Assumption},
+    trace::{self, Level},
+};
+
+pub const ASSUMPTIONS: &[Assumption] = &[Assumption {
+    name: "the three wires are already cut between sds and hbr",
+    check: wires_are_cut,
+}];
+
+pub fn part1(input: &str) -> Answer {
+    // Input has been reduced manually by visualising using GraphViz and inspecting & removing the
+    // links:
+    // sds -> hbr
+    // dqf -> cbx
+    // pzv -> xft
+    // So we can just count the subgraph of nodes from sds and hbr to get the two answers.
+    //
+    //   cargo run --bin 25 -- --trace day25=debug 2> graph.dot
+    //
+    // draws the graph, which is how inputs/2023/25-*.dot were made along the way.
+    trace::render("day25", Level::Debug, |out| write_dot(out, input));
+    let graph = Graph::from_input(input);
+    let part1 = graph.count_sub_graph("sds") * graph.count_sub_graph("hbr");
+
+    part1.into()
+}
+
+fn wires_are_cut(input: &str) -> Result<(), String> {
+    let graph = Graph::from_input(input);
+    for name in ["sds", "hbr"] {
+        if !graph.nodes.contains_key(name) {
+            return Err(format!("there is no {} component", name));
+        }
+    }
+    let total = graph.nodes.len();
+    let sds = graph.count_sub_graph("sds");
+    let hbr = graph.count_sub_graph("hbr");
+    if sds + hbr != total {
+        return Err(format!(
+            "sds and hbr reach {} and {} of the {} components",
+            sds, hbr, total
+        ));
+    }
+    Ok(())
+}
+
+// The wiring as a GraphViz graph, one line per line of the input.
+fn write_dot(out: &mut impl Write, input: &str) -> fmt::Result {
+    writeln!(out, "graph {{")?;
+    writeln!(out, "    node [shape=circle];")?;
+    for l in input.lines() {
+        let (node, neighbours) = l.split_once(": ").unwrap();
+        writeln!(out, "    {} -- {{{}}};", node, neighbours)?;
+    }
+    writeln!(out, "}}")
+}
+
+struct Graph {
+    nodes: HashMap<String, Node>,
+}
+
+impl Graph {
+    fn from_input(s: &str) -> Self {
+        let mut nodes = HashMap::new();
+        // jqt: rhn xhk nvd
+        for l in s.lines() {
+            let (node_str, neighbours_str) = l.split_once(": ").unwrap();
+            let node = nodes.entry(node_str.to_string()).or_insert(Node {
+                name: node_str.to_string(),
+                neighbours: HashSet::new(),
+            });
+
+            let neighbours = neighbours_str.split(' ').collect_vec();
+
+            // Need two loops otherwise the borrow checker is unhappy.
+            for n in neighbours.iter() {
+                node.neighbours.insert(n.to_string());
+            }
+
+            for n in neighbours {
+                let neighbour_node = nodes.entry(n.to_string()).or_insert(Node {
+                    name: n.to_string(),
+                    neighbours: HashSet::new(),
+                });
+                neighbour_node.neighbours.insert(node_str.to_string());
+            }
+        }
+
+        Self { nodes }
+    }
+
+    fn count_sub_graph(&self, start: &str) -> usize {
+        let mut visited = HashSet::new();
+        let start_node = self.nodes.get(start).unwrap();
+
+        let mut queue = VecDeque::new();
+        visited.insert(start);
+        queue.push_back(start_node);
+
+        while let Some(node) = queue.pop_front() {
+            visited.insert(&node.name);
+            for n in &node.neighbours {
+                if !visited.contains(n.as_str()) {
+                    let neighbour_node = self.nodes.get(n).unwrap();
+                    queue.push_back(neighbour_node);
+                }
+            }
+        }
+
+        visited.len()
+    }
+}
+
+struct Node {
+    name: String,
+    neighbours: HashSet<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::snapshot::assert_snapshot;
+
+    #[test]
+    fn test_write_dot() {
+        let input = "jqt: rhn xhk nvd
+rsh: frs pzl lsr
+xhk: hfx
+cmg: qnr nvd lhk bvb
+rhn: xhk bvb hfx
+bvb: xhk hfx
+pzl: lsr hfx nvd
+qnr: nvd
+ntq: jqt hfx bvb xhk
+nvd: lhk
+lsr: lhk
+rzs: qnr cmg lsr rsh
+frs: qnr lhk lsr";
+        let mut out = String::new();
+        write_dot(&mut out, input).unwrap();
+        assert_snapshot("2023/day25-graph.dot", &out);
+    }
+}
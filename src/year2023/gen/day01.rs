@@ -0,0 +1,48 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "lines",
+        default: 1000,
+        help: "number of calibration lines",
+    },
+    Param {
+        name: "words",
+        default: 40,
+        help: "chance out of 100 that a number is spelled out",
+    },
+];
+
+const WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    (0..params.size("lines"))
+        .map(|_| line(rng, params.get("words")))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Junk letters with numbers mixed in. Part 1 needs at least one digit on every line.
+fn line(rng: &mut Rng, words: i64) -> String {
+    let mut line = String::new();
+    let mut has_digit = false;
+    for _ in 0..rng.range(1..8) {
+        for _ in 0..rng.range(0..5) {
+            line.push((b'a' + rng.below(26) as u8) as char);
+        }
+        if rng.percent(words) {
+            let word = *rng.choose(&WORDS);
+            line.push_str(word);
+        } else {
+            line.push((b'1' + rng.below(9) as u8) as char);
+            has_digit = true;
+        }
+    }
+    if !has_digit {
+        let at = rng.index(line.len() + 1);
+        line.insert(at, (b'1' + rng.below(9) as u8) as char);
+    }
+    line
+}
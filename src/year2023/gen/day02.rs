@@ -0,0 +1,36 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "lines",
+        default: 100,
+        help: "number of games",
+    },
+    Param {
+        name: "max",
+        default: 20,
+        help: "most cubes of one colour shown at once",
+    },
+];
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let max = params.get("max").max(1);
+    (1..=params.size("lines"))
+        .map(|id| {
+            let pulls: Vec<String> = (0..rng.range(1..7))
+                .map(|_| {
+                    let mut colours = ["red", "green", "blue"];
+                    rng.shuffle(&mut colours);
+                    let shown = rng.range(1..4) as usize;
+                    colours[..shown]
+                        .iter()
+                        .map(|colour| format!("{} {}", rng.range(1..max + 1), colour))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                })
+                .collect();
+            format!("Game {}: {}", id, pulls.join("; "))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,49 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "size",
+        default: 140,
+        help: "width and height of the schematic",
+    },
+    Param {
+        name: "numbers",
+        default: 8,
+        help: "chance out of 100 of a number starting on any tile",
+    },
+    Param {
+        name: "symbols",
+        default: 4,
+        help: "chance out of 100 of a symbol on any tile",
+    },
+];
+
+const SYMBOLS: &[u8] = b"*#+$/@%=&-";
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let size = params.size("size");
+    let mut grid = vec![vec![b'.'; size]; size];
+    for row in grid.iter_mut() {
+        let mut x = 0;
+        while x < size {
+            if rng.percent(params.get("numbers")) {
+                // Numbers have 1 to 3 digits and always have a tile of space after them, so two
+                // numbers never run into each other.
+                let len = (rng.range(1..4) as usize).min(size - x);
+                let number = rng.range(10_i64.pow(len as u32 - 1)..10_i64.pow(len as u32));
+                row[x..x + len].copy_from_slice(number.to_string().as_bytes());
+                x += len + 1;
+            } else {
+                if rng.percent(params.get("symbols")) {
+                    row[x] = *rng.choose(SYMBOLS);
+                }
+                x += 1;
+            }
+        }
+    }
+
+    grid.into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,66 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "lines",
+        default: 200,
+        help: "number of cards",
+    },
+    Param {
+        name: "winning",
+        default: 10,
+        help: "winning numbers per card",
+    },
+    Param {
+        name: "have",
+        default: 25,
+        help: "numbers you have per card",
+    },
+];
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let cards = params.size("lines");
+    // Numbers are unique within each list, so neither list can hold more than 1 to 99.
+    let winning = params.size("winning").min(99);
+    let have = params.size("have").min(99);
+    let id_width = cards.to_string().len();
+
+    // Won copies multiply quickly, so like the real cards these come in runs that end in a
+    // card without any matches, and no card wins copies past the end of its run.
+    let mut run_end = 0;
+    (1..=cards)
+        .map(|id| {
+            if id > run_end {
+                run_end = id + rng.range(8..16) as usize;
+            }
+            let matches = rng.index(winning.min(have).min(run_end - id) + 1);
+
+            let mut numbers: Vec<i64> = (1..100).collect();
+            rng.shuffle(&mut numbers);
+            let winning_numbers = &numbers[..winning];
+
+            // The numbers we have are the matching ones and then ones drawn from the others.
+            let others = (have - matches).min(99 - winning);
+            let mut have_numbers = numbers[winning..winning + others].to_vec();
+            have_numbers.extend(&numbers[..matches]);
+            rng.shuffle(&mut have_numbers);
+
+            format!(
+                "Card {:>width$}: {} | {}",
+                id,
+                column(winning_numbers),
+                column(&have_numbers),
+                width = id_width
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn column(numbers: &[i64]) -> String {
+    numbers
+        .iter()
+        .map(|n| format!("{:>2}", n))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
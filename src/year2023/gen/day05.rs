@@ -0,0 +1,73 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "max",
+        default: 1_000_000,
+        help: "every number is below this (part 2 counts up to its answer one at a time)",
+    },
+    Param {
+        name: "seeds",
+        default: 10,
+        help: "number of seed ranges",
+    },
+    Param {
+        name: "ranges",
+        default: 30,
+        help: "ranges per map",
+    },
+];
+
+const MAPS: [&str; 7] = [
+    "seed-to-soil",
+    "soil-to-fertilizer",
+    "fertilizer-to-water",
+    "water-to-light",
+    "light-to-temperature",
+    "temperature-to-humidity",
+    "humidity-to-location",
+];
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let max = params.get("max").max(2);
+
+    let seeds: Vec<String> = (0..params.size("seeds"))
+        .map(|_| {
+            let start = rng.range(0..max - 1);
+            let len = rng.range(1..(max / 20).max(2)).min(max - start);
+            format!("{} {}", start, len)
+        })
+        .collect();
+    let mut sections = vec![format!("seeds: {}", seeds.join(" "))];
+
+    for name in MAPS {
+        sections.push(format!(
+            "{} map:\n{}",
+            name,
+            map(rng, max, params.size("ranges"))
+        ));
+    }
+    sections.join("\n\n")
+}
+
+// Cuts 0..max into ranges and lays them out again in a random order, so every number maps to
+// exactly one other.
+fn map(rng: &mut Rng, max: i64, ranges: usize) -> String {
+    let mut cuts: Vec<i64> = (1..ranges).map(|_| rng.range(1..max)).collect();
+    cuts.extend([0, max]);
+    cuts.sort_unstable();
+    cuts.dedup();
+    let sources: Vec<(i64, i64)> = cuts.windows(2).map(|w| (w[0], w[1] - w[0])).collect();
+
+    let mut order: Vec<usize> = (0..sources.len()).collect();
+    rng.shuffle(&mut order);
+    let mut dest = 0;
+    let mut lines = vec![];
+    for i in order {
+        let (src, len) = sources[i];
+        lines.push(format!("{} {} {}", dest, src, len));
+        dest += len;
+    }
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
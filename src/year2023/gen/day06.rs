@@ -0,0 +1,42 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "races",
+        default: 4,
+        help: "number of races",
+    },
+    Param {
+        name: "time",
+        default: 99,
+        help: "longest race (part 2 tries every hold of the races' digits joined up)",
+    },
+];
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let longest = params.get("time").max(2);
+    let races: Vec<(i64, i64)> = (0..params.size("races"))
+        .map(|_| {
+            let time = rng.range(2..longest + 1);
+            // Holding for half the race goes the furthest, the record has to be beatable.
+            let best = (time / 2) * (time - time / 2);
+            (time, rng.range(0..best))
+        })
+        .collect();
+
+    let width = races
+        .iter()
+        .map(|(_, distance)| distance.to_string().len())
+        .max()
+        .unwrap()
+        + 3;
+    let times: String = races
+        .iter()
+        .map(|(time, _)| format!("{:>width$}", time, width = width))
+        .collect();
+    let distances: String = races
+        .iter()
+        .map(|(_, distance)| format!("{:>width$}", distance, width = width))
+        .collect();
+    format!("Time:    {}\nDistance:{}", times, distances)
+}
@@ -0,0 +1,47 @@
+use std::collections::HashSet;
+
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "lines",
+        default: 1000,
+        help: "number of hands",
+    },
+    Param {
+        name: "bid",
+        default: 1000,
+        help: "highest bid",
+    },
+];
+
+const CARDS: &[u8] = b"AKQJT98765432";
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    // Every hand is different, otherwise the ranking isn't well defined.
+    let hands = params.size("lines").min(CARDS.len().pow(5));
+    let mut seen = HashSet::new();
+    let mut lines = vec![];
+    while lines.len() < hands {
+        let hand = hand(rng);
+        if seen.insert(hand.clone()) {
+            lines.push(format!(
+                "{} {}",
+                hand,
+                rng.range(1..params.get("bid").max(1) + 1)
+            ));
+        }
+    }
+    lines.join("\n")
+}
+
+// Drawing cards uniformly almost always gives a high card or a pair, so draw from a few
+// cards to get every kind of hand.
+fn hand(rng: &mut Rng) -> String {
+    let mut cards = CARDS.to_vec();
+    rng.shuffle(&mut cards);
+    let kinds = rng.range(1..6) as usize;
+    (0..5)
+        .map(|_| *rng.choose(&cards[..kinds]) as char)
+        .collect()
+}
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use super::{is_prime, Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "instructions",
+        default: 263,
+        help: "length of the left/right instructions",
+    },
+    Param {
+        name: "ghosts",
+        default: 6,
+        help: "number of starting nodes, one of them is AAA",
+    },
+    Param {
+        name: "cycle",
+        default: 80,
+        help: "upper bound on the length of a ghost's cycle, every ghost gets a different prime",
+    },
+];
+
+// Every ghost walks a loop of p positions, each position having two nodes. Both nodes lead to
+// the two nodes of the next position, so the instruction only picks which of the two it ends up
+// on. Both nodes of the last position end in Z, so a ghost reaches a Z after exactly p steps and
+// every p steps after that, whichever way it turned.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let length = params.size("instructions").max(2);
+    let mut instructions: Vec<char> = (0..length)
+        .map(|_| if rng.percent(50) { 'L' } else { 'R' })
+        .collect();
+    // AAA only reaches ZZZ by turning the right way into the last position, so it needs both.
+    instructions[0] = 'L';
+    instructions[1] = 'R';
+    rng.shuffle(&mut instructions);
+
+    // Primes that don't divide the number of instructions, so AAA gets to the last position
+    // with every instruction in turn.
+    let mut primes: Vec<usize> = (2..params.size("cycle").max(4) + 1)
+        .filter(|&p| is_prime(p as i64) && !length.is_multiple_of(p))
+        .collect();
+    rng.shuffle(&mut primes);
+    let ghosts = params.size("ghosts").min(primes.len());
+
+    let mut names = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+    let mut lines = vec![];
+    for (ghost, &p) in primes[..ghosts].iter().enumerate() {
+        let is_aaa = ghost == 0;
+        let start = if is_aaa {
+            "AAA".to_string()
+        } else {
+            name(rng, &mut names, 'A')
+        };
+        let mut positions: Vec<[String; 2]> = (0..p - 1)
+            .map(|_| [name(rng, &mut names, ' '), name(rng, &mut names, ' ')])
+            .collect();
+        let end = if is_aaa {
+            "ZZZ".to_string()
+        } else {
+            name(rng, &mut names, 'Z')
+        };
+        positions.push([end, name(rng, &mut names, 'Z')]);
+
+        lines.push(node_line(&start, &positions[0], rng.percent(50)));
+        for k in 0..p {
+            let next = &positions[(k + 1) % p];
+            let swap = rng.percent(50);
+            for node in &positions[k] {
+                // Both nodes before the last position turn the same way, so which of the two Z
+                // nodes AAA lands on only depends on the instruction.
+                let swap = if k == p - 2 { swap } else { rng.percent(50) };
+                lines.push(node_line(node, next, swap));
+            }
+        }
+    }
+
+    rng.shuffle(&mut lines);
+    format!(
+        "{}\n\n{}",
+        instructions.iter().collect::<String>(),
+        lines.join("\n")
+    )
+}
+
+fn node_line(node: &str, next: &[String; 2], swap: bool) -> String {
+    let (left, right) = if swap {
+        (&next[0], &next[1])
+    } else {
+        (&next[1], &next[0])
+    };
+    format!("{} = ({}, {})", node, left, right)
+}
+
+// Three uppercase letters. Only starts end in A and only ends end in Z, pass ' ' for neither.
+fn name(rng: &mut Rng, taken: &mut HashSet<String>, last: char) -> String {
+    loop {
+        let mut name: String = (0..3)
+            .map(|_| (b'B' + rng.below(24) as u8) as char)
+            .collect();
+        if last != ' ' {
+            name.replace_range(2..3, &last.to_string());
+        }
+        if taken.insert(name.clone()) {
+            return name;
+        }
+    }
+}
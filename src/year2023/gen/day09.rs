@@ -0,0 +1,43 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "lines",
+        default: 200,
+        help: "number of sequences",
+    },
+    Param {
+        name: "length",
+        default: 21,
+        help: "values per sequence",
+    },
+    Param {
+        name: "degree",
+        default: 8,
+        help: "highest degree of the polynomials behind the sequences",
+    },
+];
+
+// Each sequence is a random polynomial sampled at 0, 1, 2, ..., built up from the bottom of its
+// difference table: a row of a constant, then rows that each start at a random value.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let length = params.size("length").max(2);
+    (0..params.size("lines"))
+        .map(|_| {
+            let degree = rng.index(params.size("degree").min(length - 1) + 1);
+            let mut row = vec![rng.range(-5..6); length];
+            for _ in 0..degree {
+                let mut above = vec![rng.range(-20..21)];
+                for d in &row[..length - 1] {
+                    above.push(above.last().unwrap() + d);
+                }
+                row = above;
+            }
+            row.iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,91 @@
+use super::{shapes, Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "size",
+        default: 140,
+        help: "width and height of the field",
+    },
+    Param {
+        name: "loop",
+        default: 70,
+        help: "roughly how much of the field the loop fills, out of 100",
+    },
+    Param {
+        name: "junk",
+        default: 75,
+        help: "chance out of 100 of a tile off the loop holding a stray pipe",
+    },
+];
+
+const PIPES: &[u8] = b"|-LJ7F";
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let size = params.size("size").max(6);
+    let mut grid: Vec<Vec<u8>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    if rng.percent(params.get("junk")) {
+                        *rng.choose(PIPES)
+                    } else {
+                        b'.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+
+    // The outline of a tree has nothing inside it, so some of its rows and columns are doubled
+    // up. That takes up to 4 tiles per cell, with a border of junk around the loop.
+    let cells = (size - 1) / 4;
+    let nodes = cells * cells * params.get("loop").clamp(1, 100) as usize / 100;
+    let (outline, starts) = loop {
+        let corners = shapes::corners(&shapes::tree_outline(rng, cells, cells, nodes));
+        let xs = shapes::stretch(rng, 2 * cells, 1..3);
+        let ys = shapes::stretch(rng, 2 * cells, 1..3);
+        let corners: Vec<(i64, i64)> = corners
+            .into_iter()
+            .map(|(x, y)| (xs[x as usize], ys[y as usize]))
+            .collect();
+        let outline = shapes::walk(&corners);
+        let n = outline.len();
+        // The solution sets off downwards from S and draws it as a '|'.
+        let starts: Vec<usize> = (0..n)
+            .filter(|&i| {
+                outline[(i + n - 1) % n].0 == outline[i].0 && outline[(i + 1) % n].0 == outline[i].0
+            })
+            .collect();
+        if !starts.is_empty() {
+            break (outline, starts);
+        }
+    };
+
+    let n = outline.len();
+    for i in 0..n {
+        let (x, y) = outline[i];
+        let neighbours = [outline[(i + n - 1) % n], outline[(i + 1) % n]];
+        let connects = |dx: i64, dy: i64| neighbours.contains(&(x + dx, y + dy));
+        let pipe = match (
+            connects(0, -1),
+            connects(0, 1),
+            connects(1, 0),
+            connects(-1, 0),
+        ) {
+            (true, true, _, _) => b'|',
+            (true, _, true, _) => b'L',
+            (true, _, _, true) => b'J',
+            (_, true, _, true) => b'7',
+            (_, true, true, _) => b'F',
+            _ => b'-',
+        };
+        grid[y as usize + 1][x as usize + 1] = pipe;
+    }
+    let (x, y) = outline[*rng.choose(&starts)];
+    grid[y as usize + 1][x as usize + 1] = b'S';
+
+    grid.into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
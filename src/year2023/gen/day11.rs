@@ -0,0 +1,48 @@
+use std::collections::HashSet;
+
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "size",
+        default: 140,
+        help: "width and height of the image",
+    },
+    Param {
+        name: "galaxies",
+        default: 440,
+        help: "number of galaxies",
+    },
+    Param {
+        name: "empty",
+        default: 5,
+        help: "chance out of 100 of a row or column being left empty",
+    },
+];
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let size = params.size("size");
+    let rows: Vec<usize> = (0..size)
+        .filter(|_| !rng.percent(params.get("empty")))
+        .collect();
+    let cols: Vec<usize> = (0..size)
+        .filter(|_| !rng.percent(params.get("empty")))
+        .collect();
+
+    let mut grid = vec![vec![b'.'; size]; size];
+    if !rows.is_empty() && !cols.is_empty() {
+        let galaxies = params.size("galaxies").min(rows.len() * cols.len());
+        let mut placed = HashSet::new();
+        while placed.len() < galaxies {
+            placed.insert((*rng.choose(&rows), *rng.choose(&cols)));
+        }
+        for (y, x) in placed {
+            grid[y][x] = b'#';
+        }
+    }
+
+    grid.into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,54 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "lines",
+        default: 1000,
+        help: "number of rows of springs",
+    },
+    Param {
+        name: "length",
+        default: 20,
+        help: "longest row of springs",
+    },
+    Param {
+        name: "unknown",
+        default: 60,
+        help: "chance out of 100 of a spring's condition being unknown",
+    },
+];
+
+// Lays out a row of springs, reads the groups of damaged ones off it and then forgets the
+// condition of some of them, so every row has at least one arrangement.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let longest = params.size("length").max(2) as i64;
+    (0..params.size("lines"))
+        .map(|_| {
+            let length = rng.range(2..longest + 1) as usize;
+            let mut row: Vec<char> = (0..length)
+                .map(|_| if rng.percent(50) { '#' } else { '.' })
+                .collect();
+            if !row.contains(&'#') {
+                row[rng.index(length)] = '#';
+            }
+
+            let groups: Vec<String> = row
+                .split(|c| *c == '.')
+                .filter(|group| !group.is_empty())
+                .map(|group| group.len().to_string())
+                .collect();
+            let record: String = row
+                .iter()
+                .map(|c| {
+                    if rng.percent(params.get("unknown")) {
+                        '?'
+                    } else {
+                        *c
+                    }
+                })
+                .collect();
+            format!("{} {}", record, groups.join(","))
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,156 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "patterns",
+        default: 100,
+        help: "number of patterns",
+    },
+    Param {
+        name: "size",
+        default: 17,
+        help: "largest width and height of a pattern",
+    },
+];
+
+// A line of reflection, e.g. Rows(3) is between the 3rd and 4th row.
+#[derive(Clone, Copy, PartialEq)]
+enum Line {
+    Rows(usize),
+    Cols(usize),
+}
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let largest = params.size("size").max(5) as i64;
+    (0..params.size("patterns"))
+        .map(|_| loop {
+            let (height, width) = (
+                rng.range(5..largest + 1) as usize,
+                rng.range(5..largest + 1) as usize,
+            );
+            if let Some(pattern) = pattern(rng, height, width) {
+                break pattern;
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+// A pattern with exactly one perfect line of reflection (part 1) and exactly one line that is
+// a single smudge off (part 2). Tiles that either line reflects onto each other are tied
+// together and get the same value, except for the one pair across the second line that
+// becomes the smudge. Gives up if the random choices don't work out.
+fn pattern(rng: &mut Rng, height: usize, width: usize) -> Option<String> {
+    let lines: Vec<Line> = (1..height)
+        .map(Line::Rows)
+        .chain((1..width).map(Line::Cols))
+        .collect();
+    let perfect = *rng.choose(&lines);
+    let smudged = *rng.choose(&lines);
+    if perfect == smudged {
+        return None;
+    }
+
+    let mut pairs = reflected_pairs(smudged, height, width);
+    let smudge = pairs.swap_remove(rng.index(pairs.len()));
+    pairs.extend(reflected_pairs(perfect, height, width));
+
+    let mut sets = DisjointSets::new(height * width);
+    for (a, b) in pairs {
+        sets.union(a, b);
+    }
+    if sets.find(smudge.0) == sets.find(smudge.1) {
+        return None;
+    }
+
+    let mut values: Vec<Option<bool>> = vec![None; height * width];
+    let mut tiles = vec![false; height * width];
+    for (tile, value) in tiles.iter_mut().enumerate() {
+        let set = sets.find(tile);
+        *value = *values[set].get_or_insert_with(|| rng.percent(50));
+    }
+    let smudge_set = sets.find(smudge.1);
+    if tiles[smudge.0] == tiles[smudge.1] {
+        for (tile, value) in tiles.iter_mut().enumerate() {
+            if sets.find(tile) == smudge_set {
+                *value = !*value;
+            }
+        }
+    }
+
+    // Other lines can end up reflecting by chance.
+    for line in lines {
+        let differences = reflected_pairs(line, height, width)
+            .into_iter()
+            .filter(|&(a, b)| tiles[a] != tiles[b])
+            .count();
+        let expected = if line == perfect {
+            0
+        } else if line == smudged {
+            1
+        } else {
+            2
+        };
+        if differences.min(2) != expected {
+            return None;
+        }
+    }
+
+    Some(
+        tiles
+            .chunks(width)
+            .map(|row| {
+                row.iter()
+                    .map(|&t| if t { '#' } else { '.' })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+// The tiles (as y * width + x) a line of reflection maps onto each other.
+fn reflected_pairs(line: Line, height: usize, width: usize) -> Vec<(usize, usize)> {
+    let mut pairs = vec![];
+    match line {
+        Line::Rows(r) => {
+            for d in 0..r.min(height - r) {
+                for x in 0..width {
+                    pairs.push(((r - 1 - d) * width + x, (r + d) * width + x));
+                }
+            }
+        }
+        Line::Cols(c) => {
+            for d in 0..c.min(width - c) {
+                for y in 0..height {
+                    pairs.push((y * width + c - 1 - d, y * width + c + d));
+                }
+            }
+        }
+    }
+    pairs
+}
+
+struct DisjointSets {
+    parents: Vec<usize>,
+}
+
+impl DisjointSets {
+    fn new(n: usize) -> Self {
+        Self {
+            parents: (0..n).collect(),
+        }
+    }
+
+    fn find(&mut self, i: usize) -> usize {
+        if self.parents[i] != i {
+            self.parents[i] = self.find(self.parents[i]);
+        }
+        self.parents[i]
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.find(a), self.find(b));
+        self.parents[a] = b;
+    }
+}
@@ -0,0 +1,40 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "size",
+        default: 100,
+        help: "width and height of the platform",
+    },
+    Param {
+        name: "round",
+        default: 20,
+        help: "chance out of 100 of a tile holding a round rock",
+    },
+    Param {
+        name: "cube",
+        default: 15,
+        help: "chance out of 100 of a tile holding a cube-shaped rock",
+    },
+];
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let size = params.size("size");
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    let roll = rng.range(0..100);
+                    if roll < params.get("round") {
+                        'O'
+                    } else if roll < params.get("round") + params.get("cube") {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,38 @@
+use std::collections::HashSet;
+
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "steps",
+        default: 4000,
+        help: "number of steps in the initialization sequence",
+    },
+    Param {
+        name: "labels",
+        default: 500,
+        help: "number of different lens labels",
+    },
+];
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut taken = HashSet::new();
+    let labels: Vec<String> = (0..params.size("labels"))
+        .map(|_| {
+            let len = rng.range(2..7) as usize;
+            rng.name(len, &mut taken)
+        })
+        .collect();
+
+    (0..params.size("steps"))
+        .map(|_| {
+            let label = rng.choose(&labels);
+            if rng.percent(40) {
+                format!("{}-", label)
+            } else {
+                format!("{}={}", label, rng.range(1..10))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,34 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "size",
+        default: 110,
+        help: "width and height of the contraption",
+    },
+    Param {
+        name: "mirrors",
+        default: 10,
+        help: "chance out of 100 of a tile holding a mirror or splitter",
+    },
+];
+
+const DEVICES: &[char] = &['/', '\\', '|', '-'];
+
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let size = params.size("size");
+    (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| {
+                    if rng.percent(params.get("mirrors")) {
+                        *rng.choose(DEVICES)
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,34 @@
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "size",
+        default: 141,
+        help: "width and height of the city",
+    },
+    Param {
+        name: "max",
+        default: 9,
+        help: "highest heat loss of a block, from 1 to 9",
+    },
+];
+
+// The real map gets more expensive towards the middle, which the blocks here copy by taking the
+// highest of a few rolls near the centre.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let size = params.size("size") as i64;
+    let max = params.get("max").clamp(1, 9);
+    (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    let from_centre = (2 * y - size).abs().max((2 * x - size).abs());
+                    let rolls = 1 + 2 * (size - from_centre) / size;
+                    let loss = (0..rolls).map(|_| rng.range(1..max + 1)).max().unwrap();
+                    char::from_digit(loss as u32, 10).unwrap()
+                })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,83 @@
+use super::{shapes, Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "grid",
+        default: 25,
+        help: "the lagoons are drawn on a grid this wide and high before being stretched",
+    },
+    Param {
+        name: "fill",
+        default: 40,
+        help: "roughly how much of the grid the lagoons cover, out of 100",
+    },
+    Param {
+        name: "gap",
+        default: 12,
+        help: "longest stretch of one grid step in the part 1 plan",
+    },
+];
+
+// Both plans are outlines of random trees with the same number of nodes, which have the same
+// length, then stretched by different amounts. Longer edges are split until both plans have
+// as many instructions, because every line holds one instruction of each.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let grid = params.size("grid").max(2);
+    let nodes = (grid * grid * params.get("fill").clamp(1, 100) as usize / 100).max(2);
+    // The colour holds the distance in 5 hex digits.
+    let hex_gap = 0xfffff / (2 * grid as i64);
+
+    let mut part1 = plan(rng, grid, nodes, 2..params.get("gap").max(2) + 1);
+    let mut part2 = plan(rng, grid, nodes, 1..hex_gap + 1);
+    while part1.len() < part2.len() {
+        split(rng, &mut part1);
+    }
+    while part2.len() < part1.len() {
+        split(rng, &mut part2);
+    }
+
+    part1
+        .iter()
+        .zip(part2)
+        .map(|(&(d1, l1), (d2, l2))| {
+            let hex_direction = "RDLU".find(d2).unwrap();
+            format!("{} {} (#{:05x}{})", d1, l1, l2, hex_direction)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn plan(rng: &mut Rng, grid: usize, nodes: usize, gap: std::ops::Range<i64>) -> Vec<(char, i64)> {
+    let corners = shapes::corners(&shapes::tree_outline(rng, grid, grid, nodes));
+    let xs = shapes::stretch(rng, 2 * grid, gap.clone());
+    let ys = shapes::stretch(rng, 2 * grid, gap);
+    let n = corners.len();
+    (0..n)
+        .map(|i| {
+            let (x0, y0) = corners[i];
+            let (x1, y1) = corners[(i + 1) % n];
+            let dx = xs[x1 as usize] - xs[x0 as usize];
+            let dy = ys[y1 as usize] - ys[y0 as usize];
+            match (dx.signum(), dy.signum()) {
+                (1, _) => ('R', dx),
+                (-1, _) => ('L', -dx),
+                (_, 1) => ('D', dy),
+                _ => ('U', -dy),
+            }
+        })
+        .collect()
+}
+
+// Splits a random instruction that moves more than one metre into two in the same direction.
+fn split(rng: &mut Rng, plan: &mut Vec<(char, i64)>) {
+    let i = loop {
+        let i = rng.index(plan.len());
+        if plan[i].1 > 1 {
+            break i;
+        }
+    };
+    let (direction, length) = plan[i];
+    let first = rng.range(1..length);
+    plan[i] = (direction, first);
+    plan.insert(i + 1, (direction, length - first));
+}
@@ -0,0 +1,87 @@
+use std::collections::{HashSet, VecDeque};
+
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "workflows",
+        default: 560,
+        help: "most workflows to generate",
+    },
+    Param {
+        name: "rules",
+        default: 4,
+        help: "most rules in a workflow before its fallback",
+    },
+    Param {
+        name: "parts",
+        default: 200,
+        help: "number of part ratings",
+    },
+];
+
+const CATEGORIES: [char; 4] = ['x', 'm', 'a', 's'];
+
+// Workflows form a tree below "in", so every workflow knows which ratings can reach it. Each
+// rule splits what is left of those ratings into two non-empty parts, like in the real input,
+// so no rule is dead and no fallback is unreachable.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let mut names = HashSet::from(["in".to_string()]);
+    let mut budget = params.size("workflows") - 1;
+    let mut pending = VecDeque::from([("in".to_string(), [(1, 4000); 4])]);
+    let mut workflows = vec![];
+
+    while let Some((name, mut ranges)) = pending.pop_front() {
+        let mut target = |rng: &mut Rng, ranges: [(i64, i64); 4]| {
+            if budget > 0 && rng.percent(60) {
+                budget -= 1;
+                let len = rng.range(2..4) as usize;
+                let next = rng.name(len, &mut names);
+                pending.push_back((next.clone(), ranges));
+                next
+            } else if rng.percent(50) {
+                "A".to_string()
+            } else {
+                "R".to_string()
+            }
+        };
+
+        let mut rules = vec![];
+        for _ in 0..rng.range(1..params.get("rules").max(1) + 1) {
+            let splittable: Vec<usize> = (0..4).filter(|&i| ranges[i].0 < ranges[i].1).collect();
+            if splittable.is_empty() {
+                break;
+            }
+            let i = *rng.choose(&splittable);
+            let (min, max) = ranges[i];
+            let mut matched = ranges;
+            let rule = if rng.percent(50) {
+                let value = rng.range(min + 1..max + 1);
+                matched[i] = (min, value - 1);
+                ranges[i] = (value, max);
+                format!("{}<{}", CATEGORIES[i], value)
+            } else {
+                let value = rng.range(min..max);
+                matched[i] = (value + 1, max);
+                ranges[i] = (min, value);
+                format!("{}>{}", CATEGORIES[i], value)
+            };
+            rules.push(format!("{}:{}", rule, target(rng, matched)));
+        }
+        rules.push(target(rng, ranges));
+        workflows.push(format!("{}{{{}}}", name, rules.join(",")));
+    }
+    rng.shuffle(&mut workflows);
+
+    let parts: Vec<String> = (0..params.size("parts"))
+        .map(|_| {
+            let ratings: Vec<String> = CATEGORIES
+                .iter()
+                .map(|c| format!("{}={}", c, rng.range(1..4001)))
+                .collect();
+            format!("{{{}}}", ratings.join(","))
+        })
+        .collect();
+
+    format!("{}\n\n{}", workflows.join("\n"), parts.join("\n"))
+}
@@ -0,0 +1,72 @@
+use std::collections::HashSet;
+
+use super::{is_prime, Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "chains",
+        default: 4,
+        help: "number of counters the broadcaster feeds",
+    },
+    Param {
+        name: "bits",
+        default: 12,
+        help: "flip-flops in each counter",
+    },
+];
+
+// The same circuit as the real input. Each chain of flip-flops is a binary counter. A
+// conjunction listens to the flip-flops of the bits set in the counter's period, and once they
+// are all on it pulses the others and the first flip-flop, which resets the counter to zero.
+// The period is a prime with its top and bottom bits set, different for every counter. Each
+// counter's conjunction feeds an inverter, and the inverters feed the conjunction before rx.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let bits = params.size("bits").clamp(2, 16) as u32;
+    let mut periods: Vec<i64> = ((1 << (bits - 1)) + 1..1 << bits)
+        .step_by(2)
+        .filter(|&p| is_prime(p))
+        .collect();
+    rng.shuffle(&mut periods);
+    let chains = params.size("chains").min(periods.len());
+
+    let mut names = HashSet::from(["rx".to_string()]);
+    // Two letters like the real input, unless that runs out of names.
+    let len = if chains * (bits as usize + 2) < 200 {
+        2
+    } else {
+        3
+    };
+    let last = rng.name(len, &mut names);
+    let mut lines = vec![format!("&{} -> rx", last)];
+    let mut firsts = vec![];
+    for &period in &periods[..chains] {
+        let flip_flops: Vec<String> = (0..bits).map(|_| rng.name(len, &mut names)).collect();
+        let counter = rng.name(len, &mut names);
+        let inverter = rng.name(len, &mut names);
+
+        let mut resets = vec![];
+        for (bit, flip_flop) in flip_flops.iter().enumerate() {
+            let mut targets = vec![];
+            if let Some(next) = flip_flops.get(bit + 1) {
+                targets.push(next.clone());
+            }
+            if period & (1 << bit) != 0 {
+                targets.push(counter.clone());
+            }
+            if period & (1 << bit) == 0 || bit == 0 {
+                resets.push(flip_flop.clone());
+            }
+            rng.shuffle(&mut targets);
+            lines.push(format!("%{} -> {}", flip_flop, targets.join(", ")));
+        }
+        resets.push(inverter.clone());
+        rng.shuffle(&mut resets);
+        lines.push(format!("&{} -> {}", counter, resets.join(", ")));
+        lines.push(format!("&{} -> {}", inverter, last));
+        firsts.push(flip_flops[0].clone());
+    }
+    lines.push(format!("broadcaster -> {}", firsts.join(", ")));
+
+    rng.shuffle(&mut lines);
+    lines.join("\n")
+}
@@ -0,0 +1,64 @@
+use std::collections::VecDeque;
+
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[Param {
+    name: "rocks",
+    default: 11,
+    help: "chance out of 100 of a tile holding a rock",
+}];
+
+// Part 2 only works for the real garden's size, where 26501365 steps end exactly on the edge of
+// a copy of the garden, so that is the only size generated.
+const SIZE: usize = 131;
+
+// Like the real garden: the edges, the row and column through the start and a diamond halfway
+// to the edge are clear, and plots that can't be reached at all are rocks.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let centre = SIZE / 2;
+    let mut grid = vec![vec![b'.'; SIZE]; SIZE];
+    for (y, row) in grid.iter_mut().enumerate() {
+        for (x, tile) in row.iter_mut().enumerate() {
+            let clear = y == 0
+                || x == 0
+                || y == SIZE - 1
+                || x == SIZE - 1
+                || y == centre
+                || x == centre
+                || (y.abs_diff(centre) + x.abs_diff(centre)).abs_diff(centre) <= 1;
+            if !clear && rng.percent(params.get("rocks")) {
+                *tile = b'#';
+            }
+        }
+    }
+
+    let mut reached = vec![vec![false; SIZE]; SIZE];
+    reached[centre][centre] = true;
+    let mut queue = VecDeque::from([(centre, centre)]);
+    while let Some((y, x)) = queue.pop_front() {
+        for (ny, nx) in [
+            (y + 1, x),
+            (y.wrapping_sub(1), x),
+            (y, x + 1),
+            (y, x.wrapping_sub(1)),
+        ] {
+            if ny < SIZE && nx < SIZE && grid[ny][nx] == b'.' && !reached[ny][nx] {
+                reached[ny][nx] = true;
+                queue.push_back((ny, nx));
+            }
+        }
+    }
+    for (row, reached_row) in grid.iter_mut().zip(reached) {
+        for (tile, reached) in row.iter_mut().zip(reached_row) {
+            if !reached {
+                *tile = b'#';
+            }
+        }
+    }
+    grid[centre][centre] = b'S';
+
+    grid.into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
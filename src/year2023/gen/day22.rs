@@ -0,0 +1,63 @@
+use std::collections::HashSet;
+
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "bricks",
+        default: 1400,
+        help: "number of bricks",
+    },
+    Param {
+        name: "width",
+        default: 10,
+        help: "width and depth of the area the bricks fall in",
+    },
+    Param {
+        name: "length",
+        default: 4,
+        help: "longest brick, in cubes",
+    },
+];
+
+// Bricks are placed at random in a tower tall enough that about a tenth of it ends up filled,
+// anywhere they don't overlap a brick that is already there. Like the real snapshot, each brick only extends
+// along one axis and lists its lower end first.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let bricks = params.size("bricks");
+    let width = params.size("width") as i64;
+    let longest = params.size("length") as i64;
+    let height = bricks as i64 * longest * 6 / (width * width) + longest + 1;
+
+    let mut filled = HashSet::new();
+    let mut lines = vec![];
+    while lines.len() < bricks {
+        let axis = rng.index(3);
+        let (low, high) = if axis == 2 { (1, height) } else { (0, width) };
+        let len = rng.range(1..longest.min(high - low) + 1);
+        let mut start = [
+            rng.range(0..width),
+            rng.range(0..width),
+            rng.range(1..height),
+        ];
+        start[axis] = rng.range(low..high - len + 1);
+        let cubes: Vec<[i64; 3]> = (0..len)
+            .map(|i| {
+                let mut cube = start;
+                cube[axis] += i;
+                cube
+            })
+            .collect();
+        if cubes.iter().any(|cube| filled.contains(cube)) {
+            continue;
+        }
+        filled.extend(cubes.iter().copied());
+
+        let end = cubes.last().unwrap();
+        lines.push(format!(
+            "{},{},{}~{},{},{}",
+            start[0], start[1], start[2], end[0], end[1], end[2]
+        ));
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,98 @@
+use super::{shapes, Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "junctions",
+        default: 6,
+        help: "junctions along each side of the grid of trails",
+    },
+    Param {
+        name: "gap",
+        default: 25,
+        help: "longest trail between two junctions",
+    },
+    Param {
+        name: "missing",
+        default: 10,
+        help: "chance out of 100 of a trail between two junctions being left out",
+    },
+];
+
+// Junctions sit on a grid and trails run right and down between neighbouring ones. Slopes at
+// both ends of every trail only let you walk it right or down, so part 1 is on a DAG. Trails
+// are only left out where that keeps every junction on some path from the start to the end.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let k = params.size("junctions");
+    let gap = 4..params.get("gap").max(4) + 1;
+    let xs: Vec<usize> = shapes::stretch(rng, k, gap.clone())
+        .into_iter()
+        .map(|x| x as usize + 1)
+        .collect();
+    let ys: Vec<usize> = shapes::stretch(rng, k, gap)
+        .into_iter()
+        .map(|y| y as usize + 2)
+        .collect();
+    let (width, height) = (xs[k - 1] + 2, ys[k - 1] + 3);
+
+    // right[i][j] and down[i][j] are the trails leaving junction (i, j).
+    let mut right = vec![vec![true; k]; k];
+    let mut down = vec![vec![true; k]; k];
+    for i in 0..k {
+        right[i][k - 1] = false;
+        down[k - 1][i] = false;
+    }
+    for i in 0..k {
+        for j in 0..k {
+            let outgoing = |right: &[Vec<bool>], down: &[Vec<bool>]| right[i][j] || down[i][j];
+            if right[i][j] && rng.percent(params.get("missing")) {
+                right[i][j] = false;
+                let incoming = i > 0 && down[i - 1][j + 1];
+                if !outgoing(&right, &down) || !incoming {
+                    right[i][j] = true;
+                }
+            }
+            if down[i][j] && rng.percent(params.get("missing")) {
+                down[i][j] = false;
+                let incoming = j > 0 && right[i + 1][j - 1];
+                if !outgoing(&right, &down) || !incoming {
+                    down[i][j] = true;
+                }
+            }
+        }
+    }
+
+    let mut grid = vec![vec![b'#'; width]; height];
+    for row in &mut grid[..ys[0]] {
+        row[xs[0]] = b'.';
+    }
+    grid[ys[0] - 1][xs[0]] = b'v';
+    for row in &mut grid[ys[k - 1] + 1..] {
+        row[xs[k - 1]] = b'.';
+    }
+    grid[ys[k - 1] + 1][xs[k - 1]] = b'v';
+    for i in 0..k {
+        for j in 0..k {
+            let (y, x) = (ys[i], xs[j]);
+            grid[y][x] = b'.';
+            if right[i][j] {
+                for tile in &mut grid[y][x + 1..xs[j + 1]] {
+                    *tile = b'.';
+                }
+                grid[y][x + 1] = b'>';
+                grid[y][xs[j + 1] - 1] = b'>';
+            }
+            if down[i][j] {
+                for row in &mut grid[y + 1..ys[i + 1]] {
+                    row[x] = b'.';
+                }
+                grid[y + 1][x] = b'v';
+                grid[ys[i + 1] - 1][x] = b'v';
+            }
+        }
+    }
+
+    grid.into_iter()
+        .map(|row| String::from_utf8(row).unwrap())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
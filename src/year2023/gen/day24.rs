@@ -0,0 +1,53 @@
+use std::collections::HashSet;
+
+use super::{Param, Params, Rng};
+
+pub const PARAMS: &[Param] = &[
+    Param {
+        name: "hailstones",
+        default: 300,
+        help: "number of hailstones",
+    },
+    Param {
+        name: "speed",
+        default: 300,
+        help: "fastest a hailstone or the rock moves along one axis, per nanosecond",
+    },
+];
+
+// Throws the rock first: a start and velocity that part 2 should find again. Every hailstone
+// then gets its own time to be hit, and is placed so that it is where the rock is at that time.
+pub fn generate(rng: &mut Rng, params: &Params) -> String {
+    let speed = params.get("speed").max(1);
+    let rock_start: Vec<i64> = (0..3)
+        .map(|_| rng.range(200_000_000_000_000..400_000_000_000_000))
+        .collect();
+    let rock_velocity: Vec<i64> = (0..3).map(|_| rng.range(-speed..speed + 1)).collect();
+
+    let mut times = HashSet::new();
+    let mut lines = vec![];
+    while lines.len() < params.size("hailstones") {
+        let time = rng.range(100_000_000_000..500_000_000_000);
+        // Hailstones moving like the rock along an axis would be hit at no particular time.
+        let velocity: Vec<i64> = rock_velocity
+            .iter()
+            .map(|&v| loop {
+                let hailstone_v = rng.range(-speed..speed + 1);
+                if hailstone_v != v {
+                    break hailstone_v;
+                }
+            })
+            .collect();
+        let start: Vec<i64> = (0..3)
+            .map(|i| rock_start[i] + time * (rock_velocity[i] - velocity[i]))
+            .collect();
+        if start.iter().any(|&s| s <= 0) || !times.insert(time) {
+            continue;
+        }
+        lines.push(format!(
+            "{}, {}, {} @ {}, {}, {}",
+            start[0], start[1], start[2], velocity[0], velocity[1], velocity[2]
+        ));
+    }
+    lines.join("\n")
+}
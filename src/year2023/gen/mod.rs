@@ -0,0 +1,244 @@
+// The input generators for 2023, see gen.rs.
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+mod shapes;
+
+use crate::gen::{Generator, Param, Params, Rng};
+
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        params: day01::PARAMS,
+        generate: day01::generate,
+    },
+    Generator {
+        day: 2,
+        params: day02::PARAMS,
+        generate: day02::generate,
+    },
+    Generator {
+        day: 3,
+        params: day03::PARAMS,
+        generate: day03::generate,
+    },
+    Generator {
+        day: 4,
+        params: day04::PARAMS,
+        generate: day04::generate,
+    },
+    Generator {
+        day: 5,
+        params: day05::PARAMS,
+        generate: day05::generate,
+    },
+    Generator {
+        day: 6,
+        params: day06::PARAMS,
+        generate: day06::generate,
+    },
+    Generator {
+        day: 7,
+        params: day07::PARAMS,
+        generate: day07::generate,
+    },
+    Generator {
+        day: 8,
+        params: day08::PARAMS,
+        generate: day08::generate,
+    },
+    Generator {
+        day: 9,
+        params: day09::PARAMS,
+        generate: day09::generate,
+    },
+    Generator {
+        day: 10,
+        params: day10::PARAMS,
+        generate: day10::generate,
+    },
+    Generator {
+        day: 11,
+        params: day11::PARAMS,
+        generate: day11::generate,
+    },
+    Generator {
+        day: 12,
+        params: day12::PARAMS,
+        generate: day12::generate,
+    },
+    Generator {
+        day: 13,
+        params: day13::PARAMS,
+        generate: day13::generate,
+    },
+    Generator {
+        day: 14,
+        params: day14::PARAMS,
+        generate: day14::generate,
+    },
+    Generator {
+        day: 15,
+        params: day15::PARAMS,
+        generate: day15::generate,
+    },
+    Generator {
+        day: 16,
+        params: day16::PARAMS,
+        generate: day16::generate,
+    },
+    Generator {
+        day: 17,
+        params: day17::PARAMS,
+        generate: day17::generate,
+    },
+    Generator {
+        day: 18,
+        params: day18::PARAMS,
+        generate: day18::generate,
+    },
+    Generator {
+        day: 19,
+        params: day19::PARAMS,
+        generate: day19::generate,
+    },
+    Generator {
+        day: 20,
+        params: day20::PARAMS,
+        generate: day20::generate,
+    },
+    Generator {
+        day: 21,
+        params: day21::PARAMS,
+        generate: day21::generate,
+    },
+    Generator {
+        day: 22,
+        params: day22::PARAMS,
+        generate: day22::generate,
+    },
+    Generator {
+        day: 23,
+        params: day23::PARAMS,
+        generate: day23::generate,
+    },
+    Generator {
+        day: 24,
+        params: day24::PARAMS,
+        generate: day24::generate,
+    },
+    Generator {
+        day: 25,
+        params: day25::PARAMS,
+        generate: day25::generate,
+    },
+];
+
+fn is_prime(n: i64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::BufReader;
+
+    use super::*;
+    use crate::{gen, runner};
+
+    // Small enough that every solver gets through them quickly in a debug build.
+    fn small_params(day: u8) -> Vec<(String, i64)> {
+        let small: &[(&str, i64)] = match day {
+            1 | 2 | 4 | 7 | 9 | 12 => &[("lines", 30)],
+            3 | 10 | 11 | 14 | 16 => &[("size", 20)],
+            5 => &[("max", 2000)],
+            6 => &[("races", 3), ("time", 50)],
+            13 => &[("patterns", 10)],
+            15 => &[("steps", 50)],
+            17 => &[("size", 12)],
+            18 => &[("grid", 6)],
+            19 => &[("workflows", 30), ("parts", 20)],
+            20 => &[("chains", 3), ("bits", 8)],
+            22 => &[("bricks", 80)],
+            23 => &[("junctions", 3)],
+            24 => &[("hailstones", 20)],
+            25 => &[("components", 40)],
+            _ => &[],
+        };
+        small.iter().map(|(n, v)| (n.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn test_generated_inputs_are_solvable() {
+        for generator in GENERATORS {
+            let day = generator.day;
+            for seed in 0..3 {
+                let input = gen::generate(generator, seed, &small_params(day)).unwrap();
+                assert_eq!(
+                    input,
+                    gen::generate(generator, seed, &small_params(day)).unwrap()
+                );
+
+                let solution = runner::solution(2023, day).unwrap();
+                for (name, result) in solution.check_input(&input) {
+                    assert_eq!(result, Ok(()), "day {} seed {}: {}", day, seed, name);
+                }
+                runner::catch_panic(|| (solution.part1)(&input))
+                    .unwrap_or_else(|e| panic!("day {} seed {}: part1: {}", day, seed, e));
+                if let Some(part2) = solution.part2 {
+                    runner::catch_panic(|| part2(&input))
+                        .unwrap_or_else(|e| panic!("day {} seed {}: part2: {}", day, seed, e));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_streaming_matches_loaded_input() {
+        for generator in GENERATORS {
+            let day = generator.day;
+            let solution = runner::solution(2023, day).unwrap();
+            let Some(streaming) = &solution.streaming else {
+                continue;
+            };
+            // As it would be printed, trailing newline and all.
+            let input = gen::generate(generator, 0, &small_params(day)).unwrap() + "\n";
+            let parts = [
+                (streaming.part1, solution.part1),
+                (streaming.part2.unwrap(), solution.part2.unwrap()),
+            ];
+            for (i, (streamed, loaded)) in parts.into_iter().enumerate() {
+                // A tiny buffer, so lines are split across reads.
+                let mut reader = BufReader::with_capacity(5, input.as_bytes());
+                assert_eq!(
+                    streamed(&mut reader).unwrap(),
+                    loaded(runner::trim_input(&input)),
+                    "day {} part {}",
+                    day,
+                    i + 1
+                );
+            }
+        }
+    }
+}
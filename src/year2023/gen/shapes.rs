@@ -0,0 +1,101 @@
+// Shapes shared by more than one generator.
+use std::collections::{HashMap, HashSet};
+
+use super::Rng;
+
+// A random simple closed loop on the integer lattice, as the list of points visited in order.
+// Consecutive points (and the last and first) are one unit apart.
+//
+// The loop is the outline of a tree: `nodes` cells of a cols x rows grid are joined by a random
+// spanning tree, and the tree is drawn with every node and edge as a unit square (nodes at even
+// coordinates, edges between them). That shape has no holes and no squares touching only at a
+// corner, so its outline never touches itself.
+// Points lie in [0, 2 * cols - 1] x [0, 2 * rows - 1].
+pub fn tree_outline(rng: &mut Rng, cols: usize, rows: usize, nodes: usize) -> Vec<(i64, i64)> {
+    let nodes = nodes.clamp(1, cols * rows);
+    let start = (rng.index(cols) as i64, rng.index(rows) as i64);
+
+    let mut in_tree = HashSet::from([start]);
+    let mut squares = vec![(2 * start.0, 2 * start.1)];
+    let mut frontier = vec![start];
+    while in_tree.len() < nodes {
+        let i = rng.index(frontier.len());
+        let (x, y) = frontier[i];
+        let free: Vec<(i64, i64)> = [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+            .into_iter()
+            .filter(|&(nx, ny)| {
+                nx >= 0
+                    && ny >= 0
+                    && nx < cols as i64
+                    && ny < rows as i64
+                    && !in_tree.contains(&(nx, ny))
+            })
+            .collect();
+        if free.is_empty() {
+            frontier.swap_remove(i);
+            continue;
+        }
+        let next = *rng.choose(&free);
+        in_tree.insert(next);
+        frontier.push(next);
+        squares.push((2 * next.0, 2 * next.1));
+        squares.push((x + next.0, y + next.1));
+    }
+
+    // Walk every square anticlockwise. Edges between two squares are walked both ways and
+    // cancel out, what is left is the outline.
+    let mut edges = HashSet::new();
+    for (x, y) in squares {
+        let corners = [(x, y), (x + 1, y), (x + 1, y + 1), (x, y + 1)];
+        for i in 0..4 {
+            let edge = (corners[i], corners[(i + 1) % 4]);
+            if !edges.remove(&(edge.1, edge.0)) {
+                edges.insert(edge);
+            }
+        }
+    }
+
+    let next: HashMap<(i64, i64), (i64, i64)> = edges.into_iter().collect();
+    let first = *next.keys().min().unwrap();
+    let mut outline = vec![first];
+    let mut current = next[&first];
+    while current != first {
+        outline.push(current);
+        current = next[&current];
+    }
+    outline
+}
+
+// The corners of a loop of unit steps, i.e. the points where it changes direction.
+pub fn corners(outline: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    let n = outline.len();
+    (0..n)
+        .filter(|&i| {
+            let (prev, p, next) = (outline[(i + n - 1) % n], outline[i], outline[(i + 1) % n]);
+            (p.0 - prev.0, p.1 - prev.1) != (next.0 - p.0, next.1 - p.1)
+        })
+        .map(|i| outline[i])
+        .collect()
+}
+
+// Random increasing coordinates: 0 followed by n - 1 gaps drawn from `gap`.
+pub fn stretch(rng: &mut Rng, n: usize, gap: std::ops::Range<i64>) -> Vec<i64> {
+    let mut coords = vec![0];
+    for _ in 1..n {
+        coords.push(coords.last().unwrap() + rng.range(gap.clone()));
+    }
+    coords
+}
+
+// Joins up a loop of corners with unit steps again.
+pub fn walk(corners: &[(i64, i64)]) -> Vec<(i64, i64)> {
+    let mut outline = vec![];
+    for (i, &(x, y)) in corners.iter().enumerate() {
+        let (nx, ny) = corners[(i + 1) % corners.len()];
+        let steps = (nx - x).abs().max((ny - y).abs());
+        for step in 0..steps {
+            outline.push((x + (nx - x).signum() * step, y + (ny - y).signum() * step));
+        }
+    }
+    outline
+}
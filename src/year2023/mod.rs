@@ -0,0 +1,289 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+pub mod gen;
+
+use crate::runner::{Solution, Streaming};
+
+pub const SOLUTIONS: &[Solution] = &[
+    Solution {
+        year: 2023,
+        day: 1,
+        version: 3,
+        part1: day01::part1,
+        part2: Some(day01::part2),
+        assumptions: &[],
+        streaming: Some(Streaming {
+            part1: day01::part1_streaming,
+            part2: Some(day01::part2_streaming),
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 2,
+        version: 2,
+        part1: day02::part1,
+        part2: Some(day02::part2),
+        assumptions: &[],
+        streaming: Some(Streaming {
+            part1: day02::part1_streaming,
+            part2: Some(day02::part2_streaming),
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 3,
+        version: 2,
+        part1: day03::part1,
+        part2: Some(day03::part2),
+        assumptions: &[],
+        streaming: Some(Streaming {
+            part1: day03::part1_streaming,
+            part2: Some(day03::part2_streaming),
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 4,
+        version: 2,
+        part1: day04::part1,
+        part2: Some(day04::part2),
+        assumptions: &[],
+        streaming: Some(Streaming {
+            part1: day04::part1_streaming,
+            part2: Some(day04::part2_streaming),
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 5,
+        version: 1,
+        part1: day05::part1,
+        part2: Some(day05::part2),
+        assumptions: &[],
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 6,
+        version: 1,
+        part1: day06::part1,
+        part2: Some(day06::part2),
+        assumptions: &[],
+        streaming: Some(Streaming {
+            part1: day06::part1_streaming,
+            part2: Some(day06::part2_streaming),
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 7,
+        version: 1,
+        part1: day07::part1,
+        part2: Some(day07::part2),
+        assumptions: &[],
+        streaming: Some(Streaming {
+            part1: day07::part1_streaming,
+            part2: Some(day07::part2_streaming),
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 8,
+        version: 1,
+        part1: day08::part1,
+        part2: Some(day08::part2),
+        assumptions: day08::ASSUMPTIONS,
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 9,
+        version: 1,
+        part1: day09::part1,
+        part2: Some(day09::part2),
+        assumptions: &[],
+        streaming: Some(Streaming {
+            part1: day09::part1_streaming,
+            part2: Some(day09::part2_streaming),
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 10,
+        version: 1,
+        part1: day10::part1,
+        part2: Some(day10::part2),
+        assumptions: day10::ASSUMPTIONS,
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 11,
+        version: 1,
+        part1: day11::part1,
+        part2: Some(day11::part2),
+        assumptions: &[],
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 12,
+        version: 1,
+        part1: day12::part1,
+        part2: Some(day12::part2),
+        assumptions: &[],
+        streaming: Some(Streaming {
+            part1: day12::part1_streaming,
+            part2: Some(day12::part2_streaming),
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 13,
+        version: 1,
+        part1: day13::part1,
+        part2: Some(day13::part2),
+        assumptions: &[],
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 14,
+        version: 1,
+        part1: day14::part1,
+        part2: Some(day14::part2),
+        assumptions: &[],
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 15,
+        version: 1,
+        part1: day15::part1,
+        part2: Some(day15::part2),
+        assumptions: &[],
+        streaming: Some(Streaming {
+            part1: day15::part1_streaming,
+            part2: Some(day15::part2_streaming),
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 16,
+        version: 1,
+        part1: day16::part1,
+        part2: Some(day16::part2),
+        assumptions: &[],
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 17,
+        version: 1,
+        part1: day17::part1,
+        part2: Some(day17::part2),
+        assumptions: &[],
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 18,
+        version: 1,
+        part1: day18::part1,
+        part2: Some(day18::part2),
+        assumptions: &[],
+        streaming: Some(Streaming {
+            part1: day18::part1_streaming,
+            part2: Some(day18::part2_streaming),
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 19,
+        version: 1,
+        part1: day19::part1,
+        part2: Some(day19::part2),
+        assumptions: &[],
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 20,
+        version: 1,
+        part1: day20::part1,
+        part2: Some(day20::part2),
+        assumptions: day20::ASSUMPTIONS,
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 21,
+        version: 1,
+        part1: day21::part1,
+        part2: Some(day21::part2),
+        assumptions: day21::ASSUMPTIONS,
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 22,
+        version: 1,
+        part1: day22::part1,
+        part2: Some(day22::part2),
+        assumptions: day22::ASSUMPTIONS,
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 23,
+        version: 1,
+        part1: day23::part1,
+        part2: Some(day23::part2),
+        assumptions: &[],
+        streaming: None,
+    },
+    Solution {
+        year: 2023,
+        day: 24,
+        version: 1,
+        part1: day24::part1,
+        part2: Some(day24::part2),
+        assumptions: &[],
+        streaming: Some(Streaming {
+            part1: day24::part1_streaming,
+            part2: Some(day24::part2_streaming),
+        }),
+    },
+    Solution {
+        year: 2023,
+        day: 25,
+        version: 1,
+        part1: day25::part1,
+        part2: None,
+        assumptions: day25::ASSUMPTIONS,
+        streaming: None,
+    },
+];
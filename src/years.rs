@@ -0,0 +1,34 @@
+// Every year we have solutions for. A new year is a new yearNNNN module with its own
+// SOLUTIONS and GENERATORS, plus one entry here; nothing in the other years changes.
+use crate::{gen::Generator, runner::Solution, year2023};
+
+pub struct Year {
+    pub year: u16,
+    pub solutions: &'static [Solution],
+    pub generators: &'static [Generator],
+}
+
+pub const YEARS: &[Year] = &[Year {
+    year: 2023,
+    solutions: year2023::SOLUTIONS,
+    generators: year2023::gen::GENERATORS,
+}];
+
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|y| y.year == year)
+}
+
+// What the runner uses when no year is given.
+pub fn latest() -> &'static Year {
+    YEARS.iter().max_by_key(|y| y.year).unwrap()
+}
+
+impl Year {
+    pub fn solution(&self, day: u8) -> Option<&'static Solution> {
+        self.solutions.iter().find(|s| s.day == day)
+    }
+
+    pub fn generator(&self, day: u8) -> Option<&'static Generator> {
+        self.generators.iter().find(|g| g.day == day)
+    }
+}